@@ -3,12 +3,12 @@
 mod benches {
     extern crate test;
 
-    use septem::Roman;
+    use septem::{Digit, Roman};
     use test::{black_box, Bencher};
 
     #[bench]
     fn convert_mmmdcccxciii(b: &mut Bencher) {
-        let value = "mmmmdcccxciiii";
+        let value = "mmmdcccxciii";
         b.iter(|| black_box(value.parse::<Roman>()));
     }
 
@@ -17,4 +17,43 @@ mod benches {
         let value = 4894u32;
         b.iter(|| black_box(Roman::from_unchecked(value).to_string()));
     }
+
+    #[bench]
+    fn format_fast(b: &mut Bencher) {
+        let value = 3894u32;
+        b.iter(|| black_box(Digit::to_string_fast(value)));
+    }
+
+    #[bench]
+    fn format_digits(b: &mut Bencher) {
+        let value = 3894u32;
+        b.iter(|| black_box(Digit::to_string_slice(&Digit::from_int(value).unwrap())));
+    }
+
+    #[bench]
+    fn from_units(b: &mut Bencher) {
+        b.iter(|| {
+            for n in 1..10u32 {
+                black_box(Digit::from_units(black_box(n)).unwrap().len());
+            }
+        });
+    }
+
+    #[bench]
+    fn from_int_units(b: &mut Bencher) {
+        b.iter(|| {
+            for n in 1..10u32 {
+                black_box(Digit::from_int(black_box(n)).unwrap());
+            }
+        });
+    }
+
+    #[bench]
+    fn iter_from_int_units(b: &mut Bencher) {
+        b.iter(|| {
+            for n in 1..10u32 {
+                black_box(Digit::iter_from_int(black_box(n)).unwrap().count());
+            }
+        });
+    }
 }
@@ -0,0 +1,150 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::digit::TABLE;
+use crate::{Digit, DigitSeq, Error, Result, Roman};
+
+/// Reversed C (U+2183) used by the apostrophus notation
+pub const REVERSED_C: char = 'Ↄ';
+
+/// Apostrophus clusters used to write the thousands, from the largest to the smallest
+const CLUSTERS: &[(u32, &str)] = &[
+    (100_000, "CCCIↃↃↃ"),
+    (50_000, "IↃↃↃ"),
+    (10_000, "CCIↃↃ"),
+    (5_000, "IↃↃ"),
+    (1_000, "CIↃ"),
+];
+
+impl Digit {
+    /// Converts a string written with the apostrophus notation into Roman digits.
+    ///
+    /// Apostrophus clusters are built from an `I` followed by reversed Cs, optionally enclosed
+    /// by as many leading Cs:
+    ///
+    /// | Cluster   | Value   | Digit                    |
+    /// | --------- | ------- | ------------------------ |
+    /// | `IↃ`      | 500     | `Digit::D`               |
+    /// | `CIↃ`     | 1000    | `Digit::OneThousandOld`  |
+    /// | `IↃↃ`     | 5000    | `Digit::FiveThousand`    |
+    /// | `CCIↃↃ`   | 10 000  | `Digit::TenThousand`     |
+    /// | `IↃↃↃ`    | 50 000  | `Digit::FiftyThousand`   |
+    /// | `CCCIↃↃↃ` | 100 000 | `Digit::HundredThousand` |
+    ///
+    /// Leading Cs beyond those enclosing a cluster are ordinary hundreds, so `CCIↃ` is `C` followed
+    /// by `CIↃ`, while fewer leading Cs than reversed Cs, like `CIↃↃ`, is malformed. Every other
+    /// character is decoded with `Digit::from_char`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let digits = Digit::from_apostrophus("CIↃIↃCCXII").unwrap();
+    /// assert_eq!(1712u32, Digit::value_of(&digits));
+    ///
+    /// let digits = Digit::from_apostrophus("CCIↃↃ").unwrap();
+    /// assert_eq!(vec![Digit::TenThousand], digits);
+    ///
+    /// // A reversed C must close a cluster
+    /// assert!(Digit::from_apostrophus("XↃ").is_err());
+    /// // Clusters go no higher than three reversed Cs
+    /// assert!(Digit::from_apostrophus("IↃↃↃↃ").is_err());
+    /// ```
+    ///
+    /// Returns `DigitSeq`, or an `septem::Error::InvalidDigit` for malformed clusters
+    pub fn from_apostrophus(s: &str) -> Result<DigitSeq> {
+        let chars: Vec<char> = s.chars().collect();
+        let count =
+            |from: usize, f: fn(char) -> bool| chars[from..].iter().take_while(|&&c| f(c)).count();
+
+        let mut digits = vec![];
+        let mut i = 0;
+        while i < chars.len() {
+            let cs = count(i, |c| c == 'C' || c == 'c');
+            let is_one = matches!(chars.get(i + cs), Some('I') | Some('i'));
+            let reversed = if is_one {
+                count(i + cs + 1, |c| c == REVERSED_C)
+            } else {
+                0
+            };
+
+            if reversed == 0 {
+                if chars[i] == REVERSED_C {
+                    return Err(Error::InvalidDigit(REVERSED_C));
+                }
+                digits.extend(Digit::from_char(chars[i])?);
+                i += 1;
+                continue;
+            }
+
+            if cs > 0 && cs < reversed {
+                return Err(Error::InvalidDigit(REVERSED_C));
+            }
+            let enclosing = if cs >= reversed { reversed } else { 0 };
+            // `iter::repeat_n` needs Rust 1.82
+            #[allow(clippy::manual_repeat_n)]
+            digits.extend(core::iter::repeat(Digit::C).take(cs - enclosing));
+            digits.push(apostrophus_digit(enclosing, reversed)?);
+            i += cs + 1 + reversed;
+        }
+        Ok(digits)
+    }
+}
+
+impl Roman {
+    /// Renders the numeral in the classical inscriptional style, writing the thousands with
+    /// apostrophus clusters and the rest with standard digits.
+    ///
+    /// | Value   | Cluster   |
+    /// | ------- | --------- |
+    /// | 1000    | `CIↃ`     |
+    /// | 5000    | `IↃↃ`     |
+    /// | 10 000  | `CCIↃↃ`   |
+    /// | 50 000  | `IↃↃↃ`    |
+    /// | 100 000 | `CCCIↃↃↃ` |
+    ///
+    /// The clusters are repeated additively, like `CIↃCIↃ` for 2000, so the largest value written
+    /// with at most three hundred thousands is 399 999. `Digit::from_apostrophus` parses the
+    /// result back.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!("CIↃCMXCIV", Roman::from(1994u32).unwrap().to_apostrophus());
+    /// assert_eq!("IↃↃCIↃCIↃ", Roman::from_unchecked(7000u32).to_apostrophus());
+    /// ```
+    pub fn to_apostrophus(self) -> String {
+        let mut n = *self;
+        let mut s = String::new();
+        for &(value, cluster) in CLUSTERS {
+            while n >= value {
+                s.push_str(cluster);
+                n -= value;
+            }
+        }
+        s.extend(
+            Digit::decompose(n, TABLE)
+                .into_iter()
+                .map(Digit::to_uppercase),
+        );
+        s
+    }
+}
+
+/// Digit for a cluster with the given number of enclosing Cs and reversed Cs
+fn apostrophus_digit(enclosing: usize, reversed: usize) -> Result<Digit> {
+    use crate::Digit::*;
+    match (enclosing, reversed) {
+        (0, 1) => Ok(D),
+        (1, 1) => Ok(OneThousandOld),
+        (0, 2) => Ok(FiveThousand),
+        (2, 2) => Ok(TenThousand),
+        (0, 3) => Ok(FiftyThousand),
+        (3, 3) => Ok(HundredThousand),
+        _ => Err(Error::InvalidDigit(REVERSED_C)),
+    }
+}
@@ -1,9 +1,157 @@
+use crate::roman::pad_numeral;
 use crate::{Error, Result};
-use std::fmt::{Display, Formatter, Result as FmtResult};
-use std::ops;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{self, Display, Formatter, Result as FmtResult};
+use core::{iter, ops, str};
+
+/// Value to digits decomposition used by `Digit::from_int`, ordered from the largest value to the
+/// smallest.
+pub(crate) const TABLE: &[(u32, &[Digit])] = &[
+    (1000, &[Digit::M]),
+    (900, &[Digit::C, Digit::M]),
+    (500, &[Digit::D]),
+    (400, &[Digit::C, Digit::D]),
+    (100, &[Digit::C]),
+    (90, &[Digit::X, Digit::C]),
+    (50, &[Digit::L]),
+    (40, &[Digit::X, Digit::L]),
+    (10, &[Digit::X]),
+    (9, &[Digit::I, Digit::X]),
+    (5, &[Digit::V]),
+    (4, &[Digit::I, Digit::V]),
+    (1, &[Digit::I]),
+];
+
+/// Value to digits decomposition used by `Digit::from_int` for numbers above `Digit::MAX`, with
+/// the archaic digits and their subtractive pairs in front of the standard ones
+#[cfg(feature = "archaic")]
+const ARCHAIC_TABLE: &[(u32, &[Digit])] = &[
+    (100_000, &[Digit::HundredThousand]),
+    (90_000, &[Digit::TenThousand, Digit::HundredThousand]),
+    (50_000, &[Digit::FiftyThousand]),
+    (40_000, &[Digit::TenThousand, Digit::FiftyThousand]),
+    (10_000, &[Digit::TenThousand]),
+    (9_000, &[Digit::M, Digit::TenThousand]),
+    (5_000, &[Digit::FiveThousand]),
+    (4_000, &[Digit::M, Digit::FiveThousand]),
+    (1000, &[Digit::M]),
+    (900, &[Digit::C, Digit::M]),
+    (500, &[Digit::D]),
+    (400, &[Digit::C, Digit::D]),
+    (100, &[Digit::C]),
+    (90, &[Digit::X, Digit::C]),
+    (50, &[Digit::L]),
+    (40, &[Digit::X, Digit::L]),
+    (10, &[Digit::X]),
+    (9, &[Digit::I, Digit::X]),
+    (5, &[Digit::V]),
+    (4, &[Digit::I, Digit::V]),
+    (1, &[Digit::I]),
+];
+
+/// Digits of the numbers below 10, used by `Digit::from_units`
+const UNIT_DIGITS: [&[Digit]; 10] = [
+    &[],
+    &[Digit::I],
+    &[Digit::I, Digit::I],
+    &[Digit::I, Digit::I, Digit::I],
+    &[Digit::I, Digit::V],
+    &[Digit::V],
+    &[Digit::V, Digit::I],
+    &[Digit::V, Digit::I, Digit::I],
+    &[Digit::V, Digit::I, Digit::I, Digit::I],
+    &[Digit::I, Digit::X],
+];
+
+/// Largest number and decomposition table used by `Digit::from_int`, which only go past
+/// `Digit::MAX` with the archaic digits
+#[cfg(feature = "archaic")]
+pub(crate) const FROM_INT: (u32, &[(u32, &[Digit])]) = (Digit::MAX_ARCHAIC, ARCHAIC_TABLE);
+#[cfg(not(feature = "archaic"))]
+pub(crate) const FROM_INT: (u32, &[(u32, &[Digit])]) = (Digit::MAX, TABLE);
+
+/// The value to digits table used to convert integers into Roman numerals, ordered from the largest
+/// value to the smallest. Each entry is a single digit or a subtractive pair. With the `archaic`
+/// feature the table starts with the archaic digits, up to `(100_000, [ↈ])`.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// # #[cfg(not(feature = "archaic"))]
+/// assert_eq!((900, &[Digit::C, Digit::M][..]), VALUE_TABLE[1]);
+/// assert_eq!(Some(&(1, &[Digit::I][..])), VALUE_TABLE.last());
+/// ```
+pub const VALUE_TABLE: &[(u32, &[Digit])] = FROM_INT.1;
+
+/// Canonical numerals for each decimal digit, per place, used by `Digit::to_string_fast`
+const THOUSANDS: [&str; 4] = ["", "M", "MM", "MMM"];
+const HUNDREDS: [&str; 10] = ["", "C", "CC", "CCC", "CD", "D", "DC", "DCC", "DCCC", "CM"];
+const TENS: [&str; 10] = ["", "X", "XX", "XXX", "XL", "L", "LX", "LXX", "LXXX", "XC"];
+const UNITS: [&str; 10] = ["", "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"];
+
+/// The standard subtractive pairs and their values
+const SUBTRACTIVE_PAIRS: &[(Digit, Digit, u32)] = &[
+    (Digit::I, Digit::V, 4),
+    (Digit::I, Digit::X, 9),
+    (Digit::X, Digit::L, 40),
+    (Digit::X, Digit::C, 90),
+    (Digit::C, Digit::D, 400),
+    (Digit::C, Digit::M, 900),
+];
+
+/// Value to digits decomposition without any subtractive pairs
+pub(crate) const ADDITIVE_TABLE: &[(u32, &[Digit])] = &[
+    (1000, &[Digit::M]),
+    (500, &[Digit::D]),
+    (100, &[Digit::C]),
+    (50, &[Digit::L]),
+    (10, &[Digit::X]),
+    (5, &[Digit::V]),
+    (1, &[Digit::I]),
+];
+
+/// Sequence of digits returned by `Digit::from_int`, `Digit::from_char` and `Roman::to_digits`
+pub type DigitSeq = Vec<Digit>;
+
+/// Sequence of digits stored inline for up to 16 digits, which covers every value up to
+/// `Digit::MAX`, returned by `Digit::from_int_inline`, `Digit::from_char_inline` and
+/// `Roman::to_digits_inline` so converting does not allocate
+#[cfg(feature = "smallvec")]
+pub type InlineDigits = smallvec::SmallVec<[Digit; 16]>;
+
+/// Characters that carry no meaning in a numeral and are skipped by lenient parsing: whitespace,
+/// control characters, and invisible format characters like bidi marks and zero-width spaces
+fn is_ignorable(c: char) -> bool {
+    c.is_whitespace()
+        || c.is_control()
+        || matches!(
+            c,
+            '\u{00AD}'
+                | '\u{200B}'..='\u{200F}'
+                | '\u{202A}'..='\u{202E}'
+                | '\u{2060}'..='\u{2064}'
+                | '\u{2066}'..='\u{2069}'
+                | '\u{FEFF}'
+        )
+}
+
+/// Rendering style for `Digit::from_int_with`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Style {
+    /// Standard subtractive form, as produced by `Digit::from_int`
+    #[default]
+    Standard,
+    /// Clock face form, where a four in the units place is written `IIII` instead of `IV`
+    Clock,
+}
 
 /// Representation of a roman digit
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Digit {
     I,
     V,
@@ -23,174 +171,1179 @@ pub enum Digit {
     FiftyThousand, // ↇ
     #[cfg(feature = "archaic")]
     HundredThousand, // ↈ
+
+    /// Medieval zero, written `N` for *nulla*
+    #[cfg(feature = "nulla")]
+    Nulla,
 }
 
-impl Digit {
-    /// Converts any positive integer into a vector of Roman digits.
+/// Values of every digit, indexed by declaration order
+const BASE_VALUES: &[u32] = &[
+    1,
+    5,
+    10,
+    50,
+    100,
+    500,
+    1000,
+    #[cfg(feature = "archaic")]
+    1000,
+    #[cfg(feature = "archaic")]
+    5000,
+    #[cfg(feature = "archaic")]
+    10000,
+    #[cfg(feature = "archaic")]
+    50000,
+    #[cfg(feature = "archaic")]
+    100000,
+    #[cfg(feature = "nulla")]
+    0,
+];
+
+impl Digit {
+    /// The largest value that can be written as a standard roman numeral.
+    pub const MAX: u32 = 3999;
+
+    /// The largest value `Digit::from_int` writes with the archaic digits, `ↈↈↈↂↈMↂCMXCIX`, as
+    /// there is no digit above a hundred thousand to continue the runs of `ↈ`.
+    #[cfg(feature = "archaic")]
+    pub const MAX_ARCHAIC: u32 = 399_999;
+
+    /// The longest ASCII rendering of a number up to `Digit::MAX`, `MMMDCCCLXXXVIII` for 3888.
+    /// A buffer of this size always fits the output of `Digit::write_ascii`.
+    pub const MAX_ASCII_LEN: usize = 15;
+
+    /// Checks whether a number can be rendered as a roman numeral, without doing the conversion.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert!(Digit::is_representable(1));
+    /// assert!(Digit::is_representable(Digit::MAX));
+    /// assert!(!Digit::is_representable(0));
+    /// # #[cfg(not(feature = "archaic"))]
+    /// assert!(!Digit::is_representable(Digit::MAX + 1));
+    /// ```
+    ///
+    /// Returns `true` if the number is in the range `1..=Digit::MAX`, or `1..=Digit::MAX_ARCHAIC`
+    /// with the `archaic` feature
+    pub fn is_representable(n: u32) -> bool {
+        (1..=FROM_INT.0).contains(&n)
+    }
+
+    /// Converts any positive integer up to `Digit::MAX` into a vector of Roman digits. Wider types
+    /// such as `u64` are accepted as long as the value is in range. Only the standard digits are
+    /// emitted up to `Digit::MAX`, and `Digit::value_of` always gives back the same number.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let three = Digit::from_int(3u8).unwrap();
+    /// assert_eq!(three, vec![Digit::I, Digit::I, Digit::I]);
+    ///
+    /// let eight = Digit::from_int(8u8).unwrap();
+    /// assert_eq!(eight, vec![Digit::V, Digit::I, Digit::I, Digit::I]);
+    ///
+    /// let nine = Digit::from_int(9u8).unwrap();
+    /// assert_eq!(nine, vec![Digit::I, Digit::X]);
+    ///
+    /// let nineteen_ninety_four = Digit::from_int(1994u32).unwrap();
+    /// assert_eq!(
+    ///     nineteen_ninety_four,
+    ///     vec![
+    ///         Digit::M, Digit::C, Digit::M, // 1900
+    ///         Digit::X, Digit::C,           // 90
+    ///         Digit::I, Digit::V            // 4
+    ///     ]
+    /// );
+    ///
+    /// # #[cfg(not(feature = "nulla"))]
+    /// assert!(Digit::from_int(0u8).is_err(), "zero is invalid");
+    ///
+    /// assert_eq!(Digit::from_int(12u64).unwrap(), vec![Digit::X, Digit::I, Digit::I]);
+    /// match Digit::from_int(u64::MAX) {
+    ///     Err(Error::Overflow) => {}
+    ///     _ => panic!(),
+    /// }
+    /// # #[cfg(not(feature = "archaic"))]
+    /// match Digit::from_int(Digit::MAX + 1) {
+    ///     Err(Error::Overflow) => {}
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    ///
+    /// With the `nulla` feature enabled zero is written as `Digit::Nulla`.
+    /// ```rust
+    /// # #[cfg(feature = "nulla")]
+    /// # {
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!(Digit::from_int(0u8).unwrap(), vec![Digit::Nulla]);
+    /// # }
+    /// ```
+    ///
+    /// With the `archaic` feature enabled numbers up to `Digit::MAX_ARCHAIC` are accepted, with
+    /// the thousands above 3999 written using the archaic digits and the same subtractive rule,
+    /// e.g. 4000 is `Mↁ` and 60,000 is `ↇↂ`.
+    /// ```rust
+    /// # #[cfg(feature = "archaic")]
+    /// # {
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let digits = Digit::from_int(64_000u32).unwrap();
+    /// assert_eq!(digits[..], [Digit::FiftyThousand, Digit::TenThousand, Digit::M, Digit::FiveThousand]);
+    /// assert_eq!(64_000, Digit::value_of::<u32>(&digits));
+    /// # }
+    /// ```
+    ///
+    /// Returns `DigitSeq`, or an `septem::Error` if the number is zero, or an
+    /// `septem::Error::Overflow` if it is larger than `Digit::MAX`, or `Digit::MAX_ARCHAIC` with
+    /// the `archaic` feature.
+    #[must_use = "this returns the converted digits, or an error that should be handled"]
+    pub fn from_int<T>(num: T) -> Result<DigitSeq>
+    where
+        T: TryInto<u32>,
+    {
+        let n: u32 = num.try_into().map_err(|_| Error::Overflow)?;
+        if n < 10 {
+            return Digit::from_units(n).map(DigitSeq::from);
+        }
+        let (max, table) = FROM_INT;
+        Digit::from_int_in(n, max, table)
+    }
+
+    /// Converts a number into Roman digits like `Digit::from_int`, but stores them inline in an
+    /// `InlineDigits` instead of allocating a `Vec`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let digits = Digit::from_int_inline(1994u32).unwrap();
+    /// assert!(!digits.spilled());
+    /// assert_eq!(Digit::from_int(1994u32).unwrap()[..], digits[..]);
+    /// ```
+    ///
+    /// Returns `InlineDigits`, or an `septem::Error` in the same cases as `Digit::from_int`.
+    #[cfg(feature = "smallvec")]
+    pub fn from_int_inline<T>(num: T) -> Result<InlineDigits>
+    where
+        T: TryInto<u32>,
+    {
+        Digit::iter_from_int(num).map(Iterator::collect)
+    }
+
+    /// Returns the digits of a number below 10 from a lookup table, without walking the
+    /// decomposition table or allocating. `Digit::from_int` takes this path for small numbers,
+    /// which are the most common ones in numbered lists.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!(&[Digit::V, Digit::I, Digit::I][..], Digit::from_units(7).unwrap());
+    ///
+    /// match Digit::from_units(10) {
+    ///     Err(Error::Overflow) => {}
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    ///
+    /// Returns `&[Digit]`, or an `septem::Error::Overflow` for numbers of 10 and above, an
+    /// `septem::Error::InvalidNumber` for zero unless the `nulla` feature is enabled.
+    #[inline]
+    pub fn from_units(n: u32) -> Result<&'static [Digit]> {
+        match n {
+            0 => {
+                #[cfg(feature = "nulla")]
+                return Ok(&[Digit::Nulla]);
+                #[cfg(not(feature = "nulla"))]
+                return Err(Error::InvalidNumber(n));
+            }
+            1..=9 => Ok(UNIT_DIGITS[n as usize]),
+            _ => Err(Error::Overflow),
+        }
+    }
+
+    /// Converts a number into a vector of Roman digits using only additive runs, without any
+    /// subtractive pairs, so 4 is `IIII` and 1990 is `MDCCCCLXXXX`. `Digit::value_of` evaluates
+    /// the result back to the same number.
+    ///
+    /// The additive form is longer than the canonical one, up to 18 digits for 3999
+    /// (`MMMDCCCCLXXXXVIIII`) compared to 15 for the longest canonical numeral.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let four = Digit::from_int_additive(4u8).unwrap();
+    /// assert_eq!(four, vec![Digit::I, Digit::I, Digit::I, Digit::I]);
+    ///
+    /// let digits = Digit::from_int_additive(1990u32).unwrap();
+    /// assert_eq!("MDCCCCLXXXX", Digit::to_string_slice(&digits));
+    /// assert_eq!(1990u32, Digit::value_of(&digits));
+    /// ```
+    ///
+    /// Returns `DigitSeq`, or an `septem::Error` if the number is zero, or an
+    /// `septem::Error::Overflow` if it is larger than `Digit::MAX`.
+    pub fn from_int_additive<T>(num: T) -> Result<DigitSeq>
+    where
+        T: TryInto<u32>,
+    {
+        Digit::from_int_in(num, Digit::MAX, ADDITIVE_TABLE)
+    }
+
+    /// Checks that a number is in `1..=max` and decomposes it with the given table
+    fn from_int_in<T>(num: T, max: u32, table: &[(u32, &[Digit])]) -> Result<DigitSeq>
+    where
+        T: TryInto<u32>,
+    {
+        let n: u32 = num.try_into().map_err(|_| Error::Overflow)?;
+        if n > max {
+            return Err(Error::Overflow);
+        }
+        if n == 0 {
+            #[cfg(feature = "nulla")]
+            return Ok(DigitSeq::from(&[Digit::Nulla][..]));
+            #[cfg(not(feature = "nulla"))]
+            return Err(Error::InvalidNumber(n));
+        }
+
+        Ok(Digit::decompose(n, table))
+    }
+
+    /// Converts a number into a vector of Roman digits like `Digit::from_int`, but clamps out of
+    /// range numbers instead of failing. Anything above `Digit::MAX` becomes `MMMCMXCIX`, or above
+    /// `Digit::MAX_ARCHAIC` with the `archaic` feature, and anything below 1 becomes `I`, or `N`
+    /// for zero with the `nulla` feature.
+    ///
+    /// The clamping silently changes the value, so only use this where a numeral must always be
+    /// shown, e.g. in UI code, and never to store or compute with.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// # #[cfg(not(feature = "archaic"))]
+    /// assert_eq!(Digit::from_int(3999u32).unwrap(), Digit::from_int_saturating(12_000u32));
+    /// assert_eq!(Digit::from_int(1u32).unwrap(), Digit::from_int_saturating(-4i32));
+    /// ```
+    #[must_use]
+    pub fn from_int_saturating<T>(num: T) -> DigitSeq
+    where
+        T: TryInto<u32> + PartialOrd + Default,
+    {
+        let (max, table) = FROM_INT;
+        let n = if num < T::default() {
+            1
+        } else {
+            num.try_into().map_or(max, |n| n.min(max))
+        };
+        #[cfg(feature = "nulla")]
+        if n == 0 {
+            return DigitSeq::from(&[Digit::Nulla][..]);
+        }
+        Digit::decompose(n.max(1), table)
+    }
+
+    /// Converts a number into a vector of Roman digits like `Digit::from_int`, rendered in the
+    /// given `Style`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let four = Digit::from_int_with(4u8, Style::Clock).unwrap();
+    /// assert_eq!(four, vec![Digit::I, Digit::I, Digit::I, Digit::I]);
+    ///
+    /// let nine = Digit::from_int_with(9u8, Style::Clock).unwrap();
+    /// assert_eq!(nine, vec![Digit::I, Digit::X]);
+    /// ```
+    ///
+    /// Returns `DigitSeq`, or an `septem::Error` in the same cases as `Digit::from_int`.
+    pub fn from_int_with<T>(num: T, style: Style) -> Result<DigitSeq>
+    where
+        T: TryInto<u32>,
+    {
+        let mut digits = Digit::from_int(num)?;
+        if style == Style::Clock && digits.ends_with(&[Digit::I, Digit::V]) {
+            digits.truncate(digits.len() - 2);
+            digits.extend_from_slice(&[Digit::I; 4]);
+        }
+        Ok(digits)
+    }
+
+    /// Converts an arbitrary precision integer into a vector of Roman digits, in the same way as
+    /// `Digit::from_int`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    /// use num_bigint::BigUint;
+    ///
+    /// let digits = Digit::from_bigint(&BigUint::from(14u32)).unwrap();
+    /// assert_eq!(digits[..], [Digit::X, Digit::I, Digit::V]);
+    /// assert_eq!(BigUint::from(14u32), Digit::value_of(&digits));
+    /// ```
+    ///
+    /// Returns `DigitSeq`, or an `septem::Error` if the number is zero, or an
+    /// `septem::Error::Overflow` if it is larger than `Digit::MAX`, or `Digit::MAX_ARCHAIC` with
+    /// the `archaic` feature.
+    #[cfg(feature = "num-bigint")]
+    pub fn from_bigint(num: &num_bigint::BigUint) -> Result<DigitSeq> {
+        Digit::from_int(u32::try_from(num).map_err(|_| Error::Overflow)?)
+    }
+
+    /// Lazily decomposes any positive integer into Roman digits, without allocating. Yields the
+    /// same digits as `Digit::from_int`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let digits = Digit::iter_from_int(1994u32).unwrap();
+    /// assert_eq!(7, digits.len());
+    /// assert_eq!(
+    ///     digits.collect::<Vec<_>>(),
+    ///     vec![Digit::M, Digit::C, Digit::M, Digit::X, Digit::C, Digit::I, Digit::V]
+    /// );
+    ///
+    /// # #[cfg(not(feature = "nulla"))]
+    /// assert!(Digit::iter_from_int(0u8).is_err(), "zero is invalid");
+    /// ```
+    ///
+    /// Returns `Digits`, or an `septem::Error` in the same cases as `Digit::from_int`.
+    pub fn iter_from_int<T>(num: T) -> Result<Digits>
+    where
+        T: TryInto<u32>,
+    {
+        let n: u32 = num.try_into().map_err(|_| Error::Overflow)?;
+        if n > FROM_INT.0 {
+            return Err(Error::Overflow);
+        }
+        if n == 0 {
+            #[cfg(feature = "nulla")]
+            return Ok(Digits {
+                n,
+                index: FROM_INT.1.len(),
+                pending: &[Digit::Nulla],
+            });
+            #[cfg(not(feature = "nulla"))]
+            return Err(Error::InvalidNumber(n));
+        }
+
+        Ok(Digits {
+            n,
+            index: 0,
+            pending: &[],
+        })
+    }
+
+    /// Counts the digits `Digit::from_int` would produce for a number, without building the
+    /// sequence, e.g. to preallocate a buffer or to align a column of numerals.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!(7, Digit::count_for(1994).unwrap());
+    /// assert_eq!(Digit::MAX_ASCII_LEN, Digit::count_for(3888).unwrap());
+    /// assert_eq!(Digit::from_int(449u32).unwrap().len(), Digit::count_for(449).unwrap());
+    /// ```
+    ///
+    /// Returns `usize`, or an `septem::Error` in the same cases as `Digit::from_int`.
+    pub fn count_for(n: u32) -> Result<usize> {
+        let (max, table) = FROM_INT;
+        if n > max {
+            return Err(Error::Overflow);
+        }
+        if n == 0 {
+            #[cfg(feature = "nulla")]
+            return Ok(1);
+            #[cfg(not(feature = "nulla"))]
+            return Err(Error::InvalidNumber(n));
+        }
+
+        let mut rest = n;
+        let mut count = 0;
+        for &(value, digits) in table {
+            count += (rest / value) as usize * digits.len();
+            rest %= value;
+        }
+        Ok(count)
+    }
+
+    /// Writes a number as uppercase ASCII Roman digits into the start of a caller supplied buffer,
+    /// without allocating. The archaic digits have no ASCII form, so this stops at `Digit::MAX`
+    /// even with the `archaic` feature.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let mut buf = [0u8; Digit::MAX_ASCII_LEN];
+    /// let len = Digit::write_ascii(1994, &mut buf).unwrap();
+    /// assert_eq!(b"MCMXCIV", &buf[..len]);
+    ///
+    /// match Digit::write_ascii(1994, &mut buf[..4]) {
+    ///     Err(Error::BufferTooSmall(needed)) => assert_eq!(7, needed),
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    ///
+    /// Returns the number of bytes written, or an `septem::Error` if the number is zero,
+    /// `septem::Error::Overflow` if it is larger than `Digit::MAX`, or
+    /// `septem::Error::BufferTooSmall` if it does not fit in the buffer.
+    pub fn write_ascii(n: u32, buf: &mut [u8]) -> Result<usize> {
+        if n > Digit::MAX {
+            return Err(Error::Overflow);
+        }
+        let digits = Digit::iter_from_int(n)?;
+        let len = digits.len();
+        if buf.len() < len {
+            return Err(Error::BufferTooSmall(len));
+        }
+        for (byte, digit) in buf.iter_mut().zip(digits) {
+            *byte = digit.to_uppercase() as u8;
+        }
+        Ok(len)
+    }
+
+    /// Renders a number as an uppercase Roman numeral by looking up each decimal place in a
+    /// precomputed table, rather than decomposing it digit by digit. Gives the same result as
+    /// rendering `Digit::from_int`. The tables only cover the standard digits, so this stops at
+    /// `Digit::MAX` even with the `archaic` feature.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!("MCMXCIV", Digit::to_string_fast(1994).unwrap());
+    /// assert!(Digit::to_string_fast(0).is_err());
+    ///
+    /// // Also with the `archaic` feature, where `Digit::from_int(4000)` succeeds
+    /// match Digit::to_string_fast(4000) {
+    ///     Err(Error::Overflow) => {}
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    ///
+    /// Returns `String`, or an `septem::Error::InvalidNumber` for zero and an
+    /// `septem::Error::Overflow` for numbers above `Digit::MAX`, whether or not the `archaic`
+    /// feature is enabled.
+    pub fn to_string_fast(n: u32) -> Result<String> {
+        if n == 0 {
+            return Err(Error::InvalidNumber(n));
+        }
+        if n > Digit::MAX {
+            return Err(Error::Overflow);
+        }
+
+        let n = n as usize;
+        let mut s = String::with_capacity(Digit::MAX_ASCII_LEN);
+        s.push_str(THOUSANDS[n / 1000]);
+        s.push_str(HUNDREDS[n / 100 % 10]);
+        s.push_str(TENS[n / 10 % 10]);
+        s.push_str(UNITS[n % 10]);
+        Ok(s)
+    }
+
+    /// Converts a number into a vector of Roman digits, validating that it is representable, see
+    /// `Digit::is_representable`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!(Digit::try_from_int(4).unwrap(), vec![Digit::I, Digit::V]);
+    ///
+    /// match Digit::try_from_int(0) {
+    ///     Err(Error::InvalidNumber(n)) => assert_eq!(0, n),
+    ///     _ => panic!(),
+    /// }
+    ///
+    /// # #[cfg(not(feature = "archaic"))]
+    /// match Digit::try_from_int(4000) {
+    ///     Err(Error::OutOfRange(n)) => assert_eq!(4000, n),
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    ///
+    /// Returns `DigitSeq`, or an `septem::Error::InvalidNumber` for zero and an
+    /// `septem::Error::OutOfRange` for numbers above `Digit::MAX`, or `Digit::MAX_ARCHAIC` with
+    /// the `archaic` feature.
+    pub fn try_from_int(n: u32) -> Result<DigitSeq> {
+        if n == 0 {
+            return Err(Error::InvalidNumber(n));
+        }
+        if !Digit::is_representable(n) {
+            return Err(Error::OutOfRange(n));
+        }
+        Ok(Digit::decompose(n, FROM_INT.1))
+    }
+
+    /// Converts a number into digits using vinculum notation, where each digit is paired with
+    /// whether it is overlined, i.e. multiplied by 1000. See `Vinculum` for the rendering rules.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let five_thousand = Digit::from_int_vinculum(5000u32).unwrap();
+    /// assert_eq!(five_thousand, vec![(Digit::V, true)]);
+    ///
+    /// let million = Digit::from_int_vinculum(1_000_000u32).unwrap();
+    /// assert_eq!(million, vec![(Digit::M, true)]);
+    /// ```
+    ///
+    /// Returns `Vec<(Digit, bool)>`, or an `septem::Error` if the number is zero or larger than
+    /// `Vinculum::MAX`, or an `septem::Error::Overflow` if it does not fit in a `u32`.
+    pub fn from_int_vinculum<T>(num: T) -> Result<Vec<(Digit, bool)>>
+    where
+        T: TryInto<u32>,
+    {
+        let n: u32 = num.try_into().map_err(|_| Error::Overflow)?;
+        if n == 0 {
+            return Err(Error::InvalidNumber(n));
+        }
+        Ok(crate::Vinculum::from(n)?.to_digits())
+    }
+
+    /// Greedily decomposes a number using the given value to digits table
+    pub(crate) fn decompose(n: u32, table: &[(u32, &[Digit])]) -> DigitSeq {
+        let mut result = DigitSeq::with_capacity(15);
+        Digit::decompose_into(n, table, &mut result);
+        result
+    }
+
+    /// Greedily decomposes a number using the given value to digits table, appending the digits
+    /// to `result`
+    pub(crate) fn decompose_into<S>(mut n: u32, table: &[(u32, &[Digit])], result: &mut S)
+    where
+        S: Extend<Digit>,
+    {
+        for &(value, digits) in table {
+            if n == 0 {
+                break;
+            }
+
+            let count = n / value;
+            match *digits {
+                // `iter::repeat_n` needs Rust 1.82
+                #[allow(clippy::manual_repeat_n)]
+                [a] => result.extend(iter::repeat(a).take(count as usize)),
+                [a, b] => {
+                    result.extend((0..count).flat_map(|_| iter::once(a).chain(iter::once(b))))
+                }
+                _ => unreachable!(),
+            }
+
+            n %= value;
+        }
+    }
+
+    /// Returns the value to digits table driving `Digit::from_int`, the same as `VALUE_TABLE`.
+    ///
+    /// Entries are ordered from the largest value to the smallest, and each entry maps a value to
+    /// the digits rendering it, including the subtractive pairs such as `(900, [C, M])`. With the
+    /// `archaic` feature the archaic digits come first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let table = Digit::decomposition_table();
+    /// # #[cfg(not(feature = "archaic"))]
+    /// assert_eq!(table[0], (1000, &[Digit::M][..]));
+    /// # #[cfg(not(feature = "archaic"))]
+    /// assert_eq!(table[1], (900, &[Digit::C, Digit::M][..]));
+    /// # #[cfg(feature = "archaic")]
+    /// assert_eq!(table[0], (100_000, &[Digit::HundredThousand][..]));
+    /// assert_eq!(table.last(), Some(&(1, &[Digit::I][..])));
+    /// ```
+    pub fn decomposition_table() -> &'static [(u32, &'static [Digit])] {
+        FROM_INT.1
+    }
+
+    /// Returns every digit variant available with the enabled features, in declaration order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// const DIGITS: &[Digit] = Digit::all();
+    /// assert_eq!(Digit::I, DIGITS[0]);
+    /// for d in Digit::all() {
+    ///     assert_eq!(Digit::from_char(d.to_uppercase()).unwrap(), vec![*d]);
+    /// }
+    /// ```
+    pub const fn all() -> &'static [Digit] {
+        use self::Digit::*;
+        &[
+            I,
+            V,
+            X,
+            L,
+            C,
+            D,
+            M,
+            #[cfg(feature = "archaic")]
+            OneThousandOld,
+            #[cfg(feature = "archaic")]
+            FiveThousand,
+            #[cfg(feature = "archaic")]
+            TenThousand,
+            #[cfg(feature = "archaic")]
+            FiftyThousand,
+            #[cfg(feature = "archaic")]
+            HundredThousand,
+            #[cfg(feature = "nulla")]
+            Nulla,
+        ]
+    }
+
+    /// Returns the numeric value of this Roman digit, usable in const contexts.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::*;
+    ///
+    /// const TEN: u32 = Digit::X.base_value();
+    /// assert_eq!(TEN, 10);
+    /// ```
+    #[inline]
+    pub const fn base_value(self) -> u32 {
+        BASE_VALUES[self as usize]
+    }
+
+    /// Returns the `u32` value of this Roman digit in const and static initializers, the same as
+    /// `Digit::base_value`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::*;
+    ///
+    /// const V_VAL: u32 = Digit::V.value_const();
+    /// static LIMITS: [u32; 2] = [Digit::L.value_const(), Digit::C.value_const()];
+    /// assert_eq!(5, V_VAL);
+    /// assert_eq!([50, 100], LIMITS);
+    /// ```
+    #[inline]
+    pub const fn value_const(self) -> u32 {
+        self.base_value()
+    }
+
+    /// Returns the numeric value of this Roman digit as any type that implements `From<u32>`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let v: u32 = Digit::V.value();
+    /// let x: u64 = Digit::X.value();
+    /// let f: f64 = Digit::L.value();
+    ///
+    /// assert_eq!(v, 5);
+    /// assert_eq!(x, 10);
+    /// assert_eq!(f, 50.0);
+    /// ```
+    #[inline]
+    pub fn value<T>(&self) -> T
+    where
+        T: From<u32>,
+    {
+        T::from(self.base_value())
+    }
+
+    /// Returns the single digit whose value is exactly `v`, the inverse of `Digit::value`.
+    ///
+    /// `1000` maps to `Digit::M` even when the archaic `Digit::OneThousandOld` is available.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!(Some(Digit::V), Digit::from_value(5));
+    /// assert_eq!(Some(Digit::M), Digit::from_value(1000));
+    /// assert_eq!(None, Digit::from_value(3));
+    /// ```
+    pub fn from_value(v: u32) -> Option<Digit> {
+        use self::Digit::*;
+        match v {
+            1 => Some(I),
+            5 => Some(V),
+            10 => Some(X),
+            50 => Some(L),
+            100 => Some(C),
+            500 => Some(D),
+            1000 => Some(M),
+
+            #[cfg(feature = "archaic")]
+            5000 => Some(FiveThousand),
+            #[cfg(feature = "archaic")]
+            10000 => Some(TenThousand),
+            #[cfg(feature = "archaic")]
+            50000 => Some(FiftyThousand),
+            #[cfg(feature = "archaic")]
+            100000 => Some(HundredThousand),
+
+            _ => None,
+        }
+    }
+
+    /// Computes the numeric value of a Roman numeral sequence (e.g. `XIV` → `14`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let digits = vec![Digit::X, Digit::I, Digit::I];
+    /// assert_eq!(Digit::value_of::<u32>(&digits), 12);
+    ///
+    /// let digits = vec![Digit::X, Digit::I, Digit::V];
+    /// assert_eq!(Digit::value_of::<u32>(&digits), 14);
+    ///
+    /// let digits = vec![Digit::I, Digit::X];
+    /// assert_eq!(Digit::value_of::<u64>(&digits), 9);
+    /// ```
+    ///
+    /// With the `nulla` feature a `Digit::Nulla` counts as zero, a Nulla mixed with other digits
+    /// is only rejected by `Digit::checked_value_of` and `Digit::validate`.
+    ///
+    /// `T` does not need to be `Copy`, so with the `num-bigint` feature the total can be computed
+    /// exactly as a `num_bigint::BigUint`.
+    ///
+    /// The `From<u32>` bound means every digit value converts into `T` without loss, so small types
+    /// such as `u8` that could silently truncate `M` are rejected at compile time. Use
+    /// `Digit::checked_value_of` for those, which returns `septem::Error::Overflow` instead.
+    ///
+    /// ```compile_fail
+    /// # use septem::*;
+    /// let truncated: u8 = Digit::value_of(&[Digit::M]);
+    /// ```
+    pub fn value_of<T>(digits: &[Digit]) -> T
+    where
+        T: From<u32> + ops::Add<Output = T> + ops::Sub<Output = T> + PartialOrd + Default,
+    {
+        let mut total = T::default();
+        let mut i = 0;
+
+        while i < digits.len() {
+            let curr = digits[i].value::<T>();
+            if i + 1 < digits.len() {
+                let next = digits[i + 1].value::<T>();
+                if curr < next {
+                    total = total + (next - curr);
+                    i += 2;
+                    continue;
+                }
+            }
+            total = total + curr;
+            i += 1;
+        }
+
+        total
+    }
+
+    /// Computes the numeric value of a stream of chars, decoding each one like `Digit::from_char`
+    /// and evaluating it like `Digit::value_of` in a single pass, without collecting the digits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!(1994, Digit::value_of_chars("MCMXCIV".chars()).unwrap());
+    /// assert_eq!(12, Digit::value_of_chars(vec!['Ⅹ', 'i', 'i']).unwrap());
+    ///
+    /// match Digit::value_of_chars("XIV?".chars()) {
+    ///     Err(Error::InvalidDigit(c)) => assert_eq!('?', c),
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    ///
+    /// Returns `u32`, or an `septem::Error::InvalidDigit` for the first invalid char,
+    /// `septem::Error::EmptyInput` if there are no chars and `septem::Error::Overflow` if the
+    /// total does not fit in a `u32`.
+    pub fn value_of_chars<I>(chars: I) -> Result<u32>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let mut total = 0u32;
+        let mut pending: Option<u32> = None;
+        let mut empty = true;
+
+        for c in chars {
+            for digit in Digit::char_digits(c)? {
+                empty = false;
+                let curr = digit.value::<u32>();
+                let value = match pending.take() {
+                    Some(prev) if prev < curr => curr - prev,
+                    Some(prev) => {
+                        pending = Some(curr);
+                        prev
+                    }
+                    None => {
+                        pending = Some(curr);
+                        continue;
+                    }
+                };
+                total = total.checked_add(value).ok_or(Error::Overflow)?;
+            }
+        }
+
+        if empty {
+            return Err(Error::EmptyInput);
+        }
+        total
+            .checked_add(pending.unwrap_or(0))
+            .ok_or(Error::Overflow)
+    }
+
+    /// Parses the Roman numeral at the start of a string, stopping at the first character that is
+    /// not a digit, and returns it with the unparsed remainder.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let (roman, rest) = Digit::parse_prefix("XIVhello").unwrap();
+    /// assert_eq!(14, *roman);
+    /// assert_eq!("hello", rest);
+    ///
+    /// match Digit::parse_prefix("hello") {
+    ///     Err(Error::InvalidDigit(c)) => assert_eq!('h', c),
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    ///
+    /// Returns `(Roman, &str)`, or an `septem::Error::InvalidDigit` with the first character if
+    /// the string does not start with a numeral, `septem::Error::EmptyInput` for an empty string.
+    pub fn parse_prefix(s: &str) -> Result<(crate::Roman, &str)> {
+        let end = s
+            .char_indices()
+            .find(|&(_, c)| Digit::from_char(c).is_err())
+            .map_or(s.len(), |(i, _)| i);
+        let (prefix, rest) = s.split_at(end);
+        match rest.chars().next() {
+            Some(c) if prefix.is_empty() => Err(Error::InvalidDigit(c)),
+            _ => Ok((prefix.parse()?, rest)),
+        }
+    }
+
+    /// Compares the values of two digit sequences, evaluated like `Digit::value_of` in a `u128`
+    /// so that sequences too large for a `u32` still compare correctly. Sequences with the same
+    /// value are equal, even if only one of them is canonical.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// let four = [Digit::I, Digit::V];
+    /// let iiii = [Digit::I, Digit::I, Digit::I, Digit::I];
+    /// assert_eq!(Ordering::Equal, Digit::cmp_sequences(&four, &iiii));
+    /// assert_eq!(Ordering::Less, Digit::cmp_sequences(&four, &[Digit::V]));
+    /// ```
+    pub fn cmp_sequences(a: &[Digit], b: &[Digit]) -> Ordering {
+        Digit::value_of::<u128>(a).cmp(&Digit::value_of::<u128>(b))
+    }
+
+    /// Computes the numeric value of a Roman numeral sequence like `Digit::value_of`, but detects
+    /// when the total does not fit in `T`.
     ///
     /// # Examples
     /// ```rust
     /// # use septem::prelude::*;
     /// # use septem::*;
     ///
-    /// let three = Digit::from_int(3u8).unwrap();
-    /// assert_eq!(three, vec![Digit::I, Digit::I, Digit::I]);
-    ///
-    /// let eight = Digit::from_int(8u8).unwrap();
-    /// assert_eq!(eight, vec![Digit::V, Digit::I, Digit::I, Digit::I]);
-    ///
-    /// let nine = Digit::from_int(9u8).unwrap();
-    /// assert_eq!(nine, vec![Digit::I, Digit::X]);
-    ///
-    /// let nineteen_ninety_four = Digit::from_int(1994u32).unwrap();
-    /// assert_eq!(
-    ///     nineteen_ninety_four,
-    ///     vec![
-    ///         Digit::M, Digit::C, Digit::M, // 1900
-    ///         Digit::X, Digit::C,           // 90
-    ///         Digit::I, Digit::V            // 4
-    ///     ]
-    /// );
+    /// let digits = vec![Digit::M, Digit::C, Digit::M, Digit::X, Digit::C, Digit::I, Digit::V];
+    /// assert_eq!(1994u16, Digit::checked_value_of::<u16>(&digits).unwrap());
     ///
-    /// assert!(Digit::from_int(0u8).is_err(), "zero is invalid");
+    /// match Digit::checked_value_of::<u8>(&digits) {
+    ///     Err(Error::Overflow) => {}
+    ///     _ => panic!(),
+    /// }
     /// ```
     ///
-    /// Returns `Vec<Digit>`, or an `septem::Error` if the number is zero or too large.
-    pub fn from_int<T>(num: T) -> Result<Vec<Digit>>
+    /// Returns `T`, or an `septem::Error::Overflow` if the total is too large for `T`.
+    pub fn checked_value_of<T>(digits: &[Digit]) -> Result<T>
     where
-        T: Into<u32> + Copy + PartialOrd + From<u8>,
+        T: TryFrom<u32>,
     {
-        let mut n: u32 = num.into();
-        if n == 0 {
-            return Err(Error::InvalidNumber(n));
-        }
-
-        use Digit::*;
+        #[cfg(feature = "nulla")]
+        Digit::check_nulla(digits)?;
 
-        const TABLE: &[(u32, &[Digit])] = &[
-            (1000, &[M]),
-            (900, &[C, M]),
-            (500, &[D]),
-            (400, &[C, D]),
-            (100, &[C]),
-            (90, &[X, C]),
-            (50, &[L]),
-            (40, &[X, L]),
-            (10, &[X]),
-            (9, &[I, X]),
-            (5, &[V]),
-            (4, &[I, V]),
-            (1, &[I]),
-        ];
-
-        let mut result = Vec::with_capacity(15);
-
-        for &(value, digits) in TABLE {
-            if n == 0 {
-                break;
-            }
+        let mut total = 0u32;
+        let mut i = 0;
 
-            let count = n / value;
-            match digits {
-                &[a] => result.extend(std::iter::repeat(a).take(count as usize)),
-                &[a, b] => (0..count).for_each(|_| {
-                    result.push(a);
-                    result.push(b);
-                }),
-                _ => unreachable!(),
+        while i < digits.len() {
+            let curr = digits[i].value::<u32>();
+            let mut value = curr;
+            i += 1;
+            if let Some(next) = digits.get(i).map(|d| d.value::<u32>()) {
+                if curr < next {
+                    value = next - curr;
+                    i += 1;
+                }
             }
-
-            n %= value;
+            total = total.checked_add(value).ok_or(Error::Overflow)?;
         }
 
-        Ok(result)
+        T::try_from(total).map_err(|_| Error::Overflow)
     }
 
-    /// Returns the numeric value of this Roman digit as any type that implements `From<u32>`.
+    /// Computes the numeric value of a Roman numeral sequence, first checking with
+    /// `Digit::validate` that it is in canonical form. Sequences that `Digit::value_of` would
+    /// still sum up, like `IXI` or `IIII`, are rejected.
+    ///
+    /// `Digit::value_of` stays the cheaper choice for sequences that are already known to be
+    /// valid, e.g. the ones returned by `Digit::from_int`.
     ///
     /// # Examples
     /// ```rust
     /// # use septem::prelude::*;
     /// # use septem::*;
     ///
-    /// let v: u32 = Digit::V.value();
-    /// let x: u64 = Digit::X.value();
-    /// let f: f64 = Digit::L.value();
+    /// assert_eq!(14u32, Digit::value_of_checked(&[Digit::X, Digit::I, Digit::V]).unwrap());
     ///
-    /// assert_eq!(v, 5);
-    /// assert_eq!(x, 10);
-    /// assert_eq!(f, 50.0);
+    /// match Digit::value_of_checked::<u32>(&[Digit::I, Digit::X, Digit::I]) {
+    ///     Err(Error::NonCanonical(position)) => assert_eq!(2, position),
+    ///     _ => panic!(),
+    /// }
     /// ```
-    pub fn value<T>(&self) -> T
+    ///
+    /// Returns `T`, or an `septem::Error::NonCanonical` with the position of the first offending
+    /// digit, `septem::Error::EmptyInput` for an empty sequence and `septem::Error::Overflow` if
+    /// the value is too large for `T`.
+    pub fn value_of_checked<T>(digits: &[Digit]) -> Result<T>
     where
-        T: From<u32>,
+        T: TryFrom<u32>,
     {
-        let v = match self {
-            Digit::I => 1,
-            Digit::V => 5,
-            Digit::X => 10,
-            Digit::L => 50,
-            Digit::C => 100,
-            Digit::D => 500,
-            Digit::M => 1000,
-
-            #[cfg(feature = "archaic")]
-            Digit::OneThousandOld => 1000,
-            #[cfg(feature = "archaic")]
-            Digit::FiveThousand => 5000,
-            #[cfg(feature = "archaic")]
-            Digit::TenThousand => 10000,
-            #[cfg(feature = "archaic")]
-            Digit::FiftyThousand => 50000,
-            #[cfg(feature = "archaic")]
-            Digit::HundredThousand => 100000,
-        };
-        T::from(v)
+        Digit::validate(digits)?;
+        Digit::checked_value_of(digits)
     }
 
-    /// Computes the numeric value of a Roman numeral sequence (e.g. `XIV` → `14`).
+    /// Checks that a sequence of digits is a canonical Roman numeral.
+    ///
+    /// The rules enforced are the standard ones:
+    /// - `I`, `X`, `C` and `M` repeat at most three times in a row
+    /// - `V`, `L` and `D` never repeat
+    /// - the only subtractive pairs are `IV, IX, XL, XC, CD, CM`
+    /// - digits appear from the largest value to the smallest, apart from subtractive pairs
+    ///
+    /// With the `archaic` feature the same rules extend to `ↁ`, `ↂ`, `ↇ` and `ↈ`, so the
+    /// output of `Digit::from_int` is accepted up to `Digit::MAX_ARCHAIC`.
     ///
     /// # Examples
     /// ```rust
     /// # use septem::prelude::*;
     /// # use septem::*;
     ///
-    /// let digits = vec![Digit::X, Digit::I, Digit::I];
-    /// assert_eq!(Digit::value_of::<u32>(&digits), 12);
+    /// assert!(Digit::validate(&Digit::from_int(1994u32).unwrap()).is_ok());
     ///
-    /// let digits = vec![Digit::X, Digit::I, Digit::V];
-    /// assert_eq!(Digit::value_of::<u32>(&digits), 14);
+    /// match Digit::validate(&[Digit::I, Digit::I, Digit::I, Digit::I]) {
+    ///     Err(Error::NonCanonical(position)) => assert_eq!(3, position),
+    ///     _ => panic!(),
+    /// }
     ///
-    /// let digits = vec![Digit::I, Digit::X];
-    /// assert_eq!(Digit::value_of::<u64>(&digits), 9);
+    /// assert!(Digit::validate(&[Digit::V, Digit::V]).is_err());
+    /// assert!(Digit::validate(&[Digit::I, Digit::C]).is_err());
     /// ```
-    pub fn value_of<T>(digits: &[Digit]) -> T
-    where
-        T: From<u32>
-            + Copy
-            + std::ops::Add<Output = T>
-            + std::ops::Sub<Output = T>
-            + PartialOrd
-            + Default,
-    {
-        let mut total = T::default();
-        let mut i = 0;
+    ///
+    /// Returns `()`, or an `septem::Error::NonCanonical` with the position of the first offending
+    /// digit, or `septem::Error::EmptyInput` for an empty sequence.
+    pub fn validate(digits: &[Digit]) -> Result<()> {
+        use self::Digit::*;
 
-        while i < digits.len() {
-            let curr = digits[i].value::<T>();
-            if i + 1 < digits.len() {
-                let next = digits[i + 1].value::<T>();
-                if curr < next {
-                    total = total + (next - curr);
-                    i += 2;
-                    continue;
-                }
+        if digits.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+
+        #[cfg(feature = "nulla")]
+        {
+            Digit::check_nulla(digits)?;
+            if digits == [Nulla] {
+                return Ok(());
             }
-            total = total + curr;
+        }
+
+        #[cfg(not(feature = "archaic"))]
+        let (mut i, places) = (
+            digits.iter().take(3).take_while(|&&d| d == M).count(),
+            &[(C, D, M), (X, L, C), (I, V, X)][..],
+        );
+        #[cfg(feature = "archaic")]
+        let (mut i, places) = (
+            digits
+                .iter()
+                .take(3)
+                .take_while(|&&d| d == HundredThousand)
+                .count(),
+            &[
+                (TenThousand, FiftyThousand, HundredThousand),
+                (M, FiveThousand, TenThousand),
+                (C, D, M),
+                (X, L, C),
+                (I, V, X),
+            ][..],
+        );
+        for &(one, five, ten) in places {
+            i = Digit::validate_place(digits, i, one, five, ten);
+        }
+
+        if i < digits.len() {
+            return Err(Error::NonCanonical(i));
+        }
+        Ok(())
+    }
+
+    /// Rewrites a parseable but non-standard digit sequence into its canonical form, by
+    /// evaluating it and converting the value back with `Digit::from_int`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let four = Digit::canonicalize(&[Digit::I, Digit::I, Digit::I, Digit::I]).unwrap();
+    /// assert_eq!(vec![Digit::I, Digit::V], four);
+    ///
+    /// let nine = Digit::canonicalize(&[Digit::V, Digit::I, Digit::I, Digit::I, Digit::I]).unwrap();
+    /// assert_eq!(vec![Digit::I, Digit::X], nine);
+    /// ```
+    ///
+    /// Returns `DigitSeq`, or an `septem::Error` if the sequence evaluates to zero or overflows.
+    pub fn canonicalize(digits: &[Digit]) -> Result<DigitSeq> {
+        Digit::from_int(Digit::checked_value_of::<u32>(digits)?)
+    }
+
+    /// Parses a possibly non-standard numeral such as `IIII`, returning its value and whether it
+    /// was written in canonical form. Letter case does not affect canonicity.
+    ///
+    /// Whitespace, control characters and invisible format characters, such as the bidi marks
+    /// and zero-width spaces often left behind when copying from PDFs, are ignored. The strict
+    /// parsers like `Roman::from_str` still reject them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!((4, true), Digit::parse_lenient("IV").unwrap());
+    /// assert_eq!((4, false), Digit::parse_lenient("IIII").unwrap());
+    /// assert_eq!((1994, true), Digit::parse_lenient("M CM XC IV").unwrap());
+    /// assert_eq!((14, true), Digit::parse_lenient("\u{200E}XIV\u{200B}").unwrap());
+    /// ```
+    ///
+    /// Returns `(u32, bool)`, or an `septem::Error` for an invalid character,
+    /// `septem::Error::EmptyInput` if there are no digits.
+    pub fn parse_lenient(s: &str) -> Result<(u32, bool)> {
+        let mut digits = Vec::with_capacity(s.len());
+        for c in s.chars().filter(|&c| !is_ignorable(c)) {
+            digits.extend(Digit::from_char(c)?);
+        }
+        if digits.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+
+        let value = Digit::checked_value_of::<u32>(&digits)?;
+        let canonical = Digit::from_int(value).ok().as_deref() == Some(&digits[..]);
+        Ok((value, canonical))
+    }
+
+    /// Rejects a `Digit::Nulla` that is mixed with other digits
+    #[cfg(feature = "nulla")]
+    fn check_nulla(digits: &[Digit]) -> Result<()> {
+        match digits.iter().position(|&d| d == Digit::Nulla) {
+            Some(position) if digits.len() > 1 => Err(Error::NonCanonical(position)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Matches one decimal place of a canonical numeral starting at `i`, e.g. `XC`, `XL` or
+    /// `L?X{0,3}` for the tens, returning the position after it.
+    fn validate_place(
+        digits: &[Digit],
+        mut i: usize,
+        one: Digit,
+        five: Digit,
+        ten: Digit,
+    ) -> usize {
+        let at = |i: usize| digits.get(i).copied();
+
+        if at(i) == Some(one) && (at(i + 1) == Some(five) || at(i + 1) == Some(ten)) {
+            return i + 2;
+        }
+        if at(i) == Some(five) {
             i += 1;
         }
+        let ones = digits[i.min(digits.len())..]
+            .iter()
+            .take(3)
+            .take_while(|&&d| d == one)
+            .count();
+        i + ones
+    }
 
-        total
+    /// Checks whether two digits, in this order, form one of the standard subtractive pairs
+    /// `IV, IX, XL, XC, CD, CM`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert!(Digit::is_subtractive_pair(Digit::I, Digit::X));
+    /// assert!(Digit::is_subtractive_pair(Digit::C, Digit::M));
+    /// assert!(!Digit::is_subtractive_pair(Digit::I, Digit::C));
+    /// assert!(!Digit::is_subtractive_pair(Digit::X, Digit::I));
+    /// ```
+    pub fn is_subtractive_pair(first: Digit, second: Digit) -> bool {
+        SUBTRACTIVE_PAIRS
+            .iter()
+            .any(|&(a, b, _)| (a, b) == (first, second))
+    }
+
+    /// Returns the standard subtractive pairs with their values, from the smallest to the largest.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let pairs = Digit::subtractive_pairs();
+    /// assert_eq!(6, pairs.len());
+    /// assert_eq!((Digit::I, Digit::V, 4), pairs[0]);
+    /// assert_eq!((Digit::C, Digit::M, 900), pairs[5]);
+    /// ```
+    pub fn subtractive_pairs() -> &'static [(Digit, Digit, u32)] {
+        SUBTRACTIVE_PAIRS
     }
 }
 
@@ -199,8 +1352,12 @@ impl Digit {
     ///
     /// Supports:
     /// - ASCII letters: `'I', 'V', 'X', 'L', 'C', 'D', 'M'` (case-insensitive)
-    /// - Unicode Number Forms: `'Ⅰ'..'Ⅿ'` (U+2160–U+216F) and `'ⅰ'..'ⅿ'` (U+2170–U+217F)
+    /// - Unicode Number Forms: `'Ⅰ'..'Ⅿ'` (U+2160–U+216F) and `'ⅰ'..'ⅿ'` (U+2170–U+217F), and the
+    ///   late six `'ↅ'` (U+2185) and early fifty `'ↆ'` (U+2186)
     /// - (Optional) Archaic forms `'ↀ'..'ↈ'` when `feature = "archaic"` is enabled
+    /// - (Optional) Full-width Latin letters `'Ｉ', 'Ｖ', 'Ｘ', 'Ｌ', 'Ｃ', 'Ｄ', 'Ｍ'`
+    ///   (U+FF23–U+FF38) and `'ｉ', 'ｖ', 'ｘ', 'ｌ', 'ｃ', 'ｄ', 'ｍ'` (U+FF43–U+FF58) when
+    ///   `feature = "unicode"` is enabled
     ///
     /// Returns a vector of `Digit` representing the decomposed Roman numeral if applicable.
     ///
@@ -254,40 +1411,101 @@ impl Digit {
     /// # }
     /// ```
     ///
-    /// Returns `Vec<Digit>` or an [`septem::Error::InvalidDigit`].
-    pub fn from_char(c: char) -> Result<Vec<Digit>> {
+    /// # Full-width Letters
+    /// ```rust
+    /// # #[cfg(feature = "unicode")]
+    /// # {
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let m = Digit::from_char('Ｍ').unwrap();
+    /// assert_eq!(m, vec![Digit::M]);
+    ///
+    /// let x = Digit::from_char('ｘ').unwrap();
+    /// assert_eq!(x, vec![Digit::X]);
+    /// # }
+    /// ```
+    ///
+    /// Returns `DigitSeq` or an [`septem::Error::InvalidDigit`].
+    #[must_use = "this returns the converted digits, or an error that should be handled"]
+    pub fn from_char(c: char) -> Result<DigitSeq> {
+        Digit::char_digits(c).map(DigitSeq::from)
+    }
+
+    /// Converts a char into Roman digits like `Digit::from_char`, but stores them inline in an
+    /// `InlineDigits` instead of allocating a `Vec`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let viii = Digit::from_char_inline('Ⅷ').unwrap();
+    /// assert_eq!([Digit::V, Digit::I, Digit::I, Digit::I], viii[..]);
+    /// ```
+    ///
+    /// Returns `InlineDigits` or an [`septem::Error::InvalidDigit`].
+    #[cfg(feature = "smallvec")]
+    pub fn from_char_inline(c: char) -> Result<InlineDigits> {
+        Digit::char_digits(c).map(InlineDigits::from_slice)
+    }
+
+    /// Looks up the digits a char stands for, see `Digit::from_char`
+    fn char_digits(c: char) -> Result<&'static [Digit]> {
         use self::Digit::*;
 
-        let result = match c {
+        let result: &'static [Digit] = match c {
             // Single Roman numerals (ASCII + Unicode uppercase/lowercase)
-            'Ⅰ' | 'ⅰ' | 'I' | 'i' => vec![I],
-            'Ⅱ' | 'ⅱ' => vec![I, I],
-            'Ⅲ' | 'ⅲ' => vec![I, I, I],
-            'Ⅳ' | 'ⅳ' => vec![I, V],
-            'Ⅴ' | 'ⅴ' | 'V' | 'v' => vec![V],
-            'Ⅵ' | 'ⅵ' => vec![V, I],
-            'Ⅶ' | 'ⅶ' => vec![V, I, I],
-            'Ⅷ' | 'ⅷ' => vec![V, I, I, I],
-            'Ⅸ' | 'ⅸ' => vec![I, X],
-            'Ⅹ' | 'ⅹ' | 'X' | 'x' => vec![X],
-            'Ⅺ' | 'ⅺ' => vec![X, I],
-            'Ⅻ' | 'ⅻ' => vec![X, I, I],
-            'Ⅼ' | 'ⅼ' | 'L' | 'l' => vec![L],
-            'Ⅽ' | 'ⅽ' | 'C' | 'c' => vec![C],
-            'Ⅾ' | 'ⅾ' | 'D' | 'd' => vec![D],
-            'Ⅿ' | 'ⅿ' | 'M' | 'm' => vec![M],
+            'Ⅰ' | 'ⅰ' | 'I' | 'i' => &[I],
+            'Ⅱ' | 'ⅱ' => &[I, I],
+            'Ⅲ' | 'ⅲ' => &[I, I, I],
+            'Ⅳ' | 'ⅳ' => &[I, V],
+            'Ⅴ' | 'ⅴ' | 'V' | 'v' => &[V],
+            'Ⅵ' | 'ⅵ' => &[V, I],
+            'Ⅶ' | 'ⅶ' => &[V, I, I],
+            'Ⅷ' | 'ⅷ' => &[V, I, I, I],
+            'Ⅸ' | 'ⅸ' => &[I, X],
+            'Ⅹ' | 'ⅹ' | 'X' | 'x' => &[X],
+            'Ⅺ' | 'ⅺ' => &[X, I],
+            'Ⅻ' | 'ⅻ' => &[X, I, I],
+            'Ⅼ' | 'ⅼ' | 'L' | 'l' => &[L],
+            'Ⅽ' | 'ⅽ' | 'C' | 'c' => &[C],
+            'Ⅾ' | 'ⅾ' | 'D' | 'd' => &[D],
+            'Ⅿ' | 'ⅿ' | 'M' | 'm' => &[M],
+            'ↅ' => &[V, I],
+            'ↆ' => &[L],
 
             // Optional archaic numerals
             #[cfg(feature = "archaic")]
-            'ↀ' => vec![OneThousandOld],
+            'ↀ' => &[OneThousandOld],
             #[cfg(feature = "archaic")]
-            'ↁ' => vec![FiveThousand],
+            'ↁ' => &[FiveThousand],
             #[cfg(feature = "archaic")]
-            'ↂ' => vec![TenThousand],
+            'ↂ' => &[TenThousand],
             #[cfg(feature = "archaic")]
-            'ↇ' => vec![FiftyThousand],
+            'ↇ' => &[FiftyThousand],
             #[cfg(feature = "archaic")]
-            'ↈ' => vec![HundredThousand],
+            'ↈ' => &[HundredThousand],
+
+            // Optional medieval zero
+            #[cfg(feature = "nulla")]
+            'N' | 'n' => &[Nulla],
+
+            // Optional full-width Latin letters
+            #[cfg(feature = "unicode")]
+            'Ｉ' | 'ｉ' => &[I],
+            #[cfg(feature = "unicode")]
+            'Ｖ' | 'ｖ' => &[V],
+            #[cfg(feature = "unicode")]
+            'Ｘ' | 'ｘ' => &[X],
+            #[cfg(feature = "unicode")]
+            'Ｌ' | 'ｌ' => &[L],
+            #[cfg(feature = "unicode")]
+            'Ｃ' | 'ｃ' => &[C],
+            #[cfg(feature = "unicode")]
+            'Ｄ' | 'ｄ' => &[D],
+            #[cfg(feature = "unicode")]
+            'Ｍ' | 'ｍ' => &[M],
 
             _ => return Err(Error::InvalidDigit(c)),
         };
@@ -295,6 +1513,33 @@ impl Digit {
         Ok(result)
     }
 
+    /// Converts a raw codepoint from the Unicode Number Forms block, U+2160 to U+2188, into Roman
+    /// digits, in the same way as `Digit::from_char`. The archaic forms need the `archaic`
+    /// feature, and the reversed C `Ↄ` is only meaningful inside an apostrophus cluster.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let xii = Digit::from_unicode_codepoint(0x216B).unwrap();
+    /// assert_eq!(xii[..], [Digit::X, Digit::I, Digit::I]);
+    ///
+    /// let six = Digit::from_unicode_codepoint(0x2185).unwrap();
+    /// assert_eq!(six[..], [Digit::V, Digit::I]);
+    ///
+    /// assert!(Digit::from_unicode_codepoint(0x0058).is_err(), "ASCII X is outside the block");
+    /// ```
+    ///
+    /// Returns `DigitSeq` or an [`septem::Error::InvalidDigit`].
+    pub fn from_unicode_codepoint(cp: u32) -> Result<DigitSeq> {
+        let c = char::from_u32(cp).unwrap_or(char::REPLACEMENT_CHARACTER);
+        if !('\u{2160}'..='\u{2188}').contains(&c) {
+            return Err(Error::InvalidDigit(c));
+        }
+        Digit::from_char(c)
+    }
+
     /// Tries to converts a byte into a single roman digit
     ///
     /// # Examples
@@ -307,6 +1552,8 @@ impl Digit {
     /// ```
     ///
     /// Returns `Digit` , or an `septem::Error`
+    #[must_use = "this returns the converted digits, or an error that should be handled"]
+    #[inline]
     pub fn from_byte(b: u8) -> Result<Digit> {
         use self::Digit::*;
         match b {
@@ -317,10 +1564,33 @@ impl Digit {
             b'C' | b'c' => Ok(C),
             b'D' | b'd' => Ok(D),
             b'M' | b'm' => Ok(M),
+            #[cfg(feature = "nulla")]
+            b'N' | b'n' => Ok(Nulla),
             _ => Err(Error::InvalidDigit(b.into())),
         }
     }
 
+    /// Converts a buffer of ASCII bytes into roman digits, without going through `char`
+    /// decoding. This is the fast path for parsing ASCII input.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let digits = Digit::from_bytes(b"xiv").unwrap();
+    /// assert_eq!(vec![Digit::X, Digit::I, Digit::V], digits);
+    ///
+    /// assert!(Digit::from_bytes(b"XIVA").is_err());
+    /// ```
+    ///
+    /// Returns `DigitSeq`, or an `septem::Error` for the first invalid byte
+    #[must_use = "this returns the converted digits, or an error that should be handled"]
+    pub fn from_bytes(bytes: &[u8]) -> Result<DigitSeq> {
+        bytes.iter().map(|&b| Digit::from_byte(b)).collect()
+    }
+
+    #[inline]
     pub fn to_lowercase(self) -> char {
         use self::Digit::*;
         match self {
@@ -331,9 +1601,17 @@ impl Digit {
             C => 'c',
             D => 'd',
             M => 'm',
+            #[cfg(feature = "nulla")]
+            Nulla => 'n',
+            // Archaic digits have no lowercase form
+            #[cfg(feature = "archaic")]
+            OneThousandOld | FiveThousand | TenThousand | FiftyThousand | HundredThousand => {
+                self.to_uppercase()
+            }
         }
     }
 
+    #[inline]
     pub fn to_uppercase(self) -> char {
         use self::Digit::*;
         match self {
@@ -344,30 +1622,221 @@ impl Digit {
             C => 'C',
             D => 'D',
             M => 'M',
+            #[cfg(feature = "nulla")]
+            Nulla => 'N',
+            #[cfg(feature = "archaic")]
+            OneThousandOld => 'ↀ',
+            #[cfg(feature = "archaic")]
+            FiveThousand => 'ↁ',
+            #[cfg(feature = "archaic")]
+            TenThousand => 'ↂ',
+            #[cfg(feature = "archaic")]
+            FiftyThousand => 'ↇ',
+            #[cfg(feature = "archaic")]
+            HundredThousand => 'ↈ',
+        }
+    }
+
+    /// Renders a sequence of digits as an uppercase string
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let digits = Digit::from_int(1994u32).unwrap();
+    /// assert_eq!("MCMXCIV", Digit::to_string_slice(&digits));
+    /// assert_eq!("", Digit::to_string_slice(&[]));
+    /// ```
+    pub fn to_string_slice(digits: &[Digit]) -> String {
+        digits.iter().map(|d| d.to_uppercase()).collect()
+    }
+
+    /// Writes a sequence of digits as uppercase characters into any `std::fmt::Write`, without
+    /// allocating
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let mut buf = String::from("Year ");
+    /// Digit::write_slice(&Digit::from_int(1994u32).unwrap(), &mut buf).unwrap();
+    /// assert_eq!("Year MCMXCIV", buf);
+    /// ```
+    pub fn write_slice<W: fmt::Write>(digits: &[Digit], w: &mut W) -> FmtResult {
+        digits
+            .iter()
+            .try_for_each(|d| w.write_char(d.to_uppercase()))
+    }
+}
+
+/// Digits are ordered by their numeric value, so `Digit::I < Digit::X`.
+///
+/// Archaic digits sharing a value with a base digit, like `Digit::OneThousandOld` and
+/// `Digit::M`, are ordered after it.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let mut digits = vec![Digit::X, Digit::M, Digit::I, Digit::V];
+/// digits.sort();
+/// assert_eq!(vec![Digit::I, Digit::V, Digit::X, Digit::M], digits);
+/// assert_eq!(Some(&Digit::M), digits.iter().max());
+/// ```
+impl Ord for Digit {
+    fn cmp(&self, other: &Digit) -> Ordering {
+        self.value::<u32>()
+            .cmp(&other.value::<u32>())
+            .then((*self as u8).cmp(&(*other as u8)))
+    }
+}
+
+impl PartialOrd for Digit {
+    fn partial_cmp(&self, other: &Digit) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Iterator over the digits of a number, created by `Digit::iter_from_int`
+#[derive(Debug, Clone)]
+pub struct Digits {
+    /// Value left to decompose
+    n: u32,
+    /// Position in the `Digit::from_int` table of the next entry to try
+    index: usize,
+    /// Remaining digits of the entry currently being yielded
+    pending: &'static [Digit],
+}
+
+impl Iterator for Digits {
+    type Item = Digit;
+
+    fn next(&mut self) -> Option<Digit> {
+        if let Some((&first, rest)) = self.pending.split_first() {
+            self.pending = rest;
+            return Some(first);
+        }
+
+        while let Some(&(value, digits)) = FROM_INT.1.get(self.index) {
+            if self.n >= value {
+                self.n -= value;
+                self.pending = &digits[1..];
+                return Some(digits[0]);
+            }
+            self.index += 1;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut n = self.n;
+        let mut len = self.pending.len();
+        let table = FROM_INT.1;
+        for &(value, digits) in &table[self.index.min(table.len())..] {
+            len += (n / value) as usize * digits.len();
+            n %= value;
         }
+        (len, Some(len))
     }
 }
 
+impl ExactSizeIterator for Digits {}
+
 unsafe impl Send for Digit {}
 unsafe impl Sync for Digit {}
 
 impl From<Digit> for u32 {
     /// Converts from Digit to u32
+    #[inline]
     fn from(digit: Digit) -> u32 {
         *digit
     }
 }
 
+/// Adds up the values of the digits one by one
+///
+/// This is plain addition, unlike `Digit::value_of` which applies the subtractive rule, so
+/// summing `IV` gives 6 rather than 4.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let digits = vec![Digit::I, Digit::V];
+/// assert_eq!(6u32, digits.iter().sum());
+/// assert_eq!(4u32, Digit::value_of(&digits));
+/// ```
+impl iter::Sum<Digit> for u32 {
+    fn sum<I: Iterator<Item = Digit>>(iter: I) -> u32 {
+        iter.map(Digit::base_value).sum()
+    }
+}
+
+/// Adds up the values of the digits one by one, see `Sum<Digit>`
+impl<'a> iter::Sum<&'a Digit> for u32 {
+    fn sum<I: Iterator<Item = &'a Digit>>(iter: I) -> u32 {
+        iter.copied().sum()
+    }
+}
+
 impl<'a> From<&'a Digit> for char {
     /// Converts from &Digit to char
+    #[inline]
     fn from(digit: &'a Digit) -> char {
         digit.to_uppercase()
     }
 }
 
+/// Renders the digit as its uppercase char, honoring width, fill and alignment but ignoring the
+/// precision, like `Roman`
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// assert_eq!("X", Digit::X.to_string());
+/// assert_eq!("  X", format!("{:>3}", Digit::X));
+/// assert_eq!("X", format!("{:.0}", Digit::X));
+/// ```
 impl Display for Digit {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(f, "{}", char::from(self))
+        pad_numeral(f, char::from(self).encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl str::FromStr for Digit {
+    type Err = Error;
+
+    /// Parses a string holding exactly one Roman digit
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!(Digit::X, "X".parse().unwrap());
+    /// assert_eq!(Digit::D, "d".parse().unwrap());
+    ///
+    /// match "IV".parse::<Digit>() {
+    ///     Err(Error::NotSingleDigit) => {}
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    ///
+    /// Returns `Digit` , or an `septem::Error`, `Error::NotSingleDigit` if the string holds more
+    /// than one digit
+    fn from_str(s: &str) -> Result<Digit> {
+        let mut chars = s.chars();
+        let c = chars.next().ok_or(Error::EmptyInput)?;
+        match (Digit::from_char(c)?.as_slice(), chars.next()) {
+            (&[digit], None) => Ok(digit),
+            _ => Err(Error::NotSingleDigit),
+        }
     }
 }
 
@@ -375,16 +1844,8 @@ impl ops::Deref for Digit {
     type Target = u32;
 
     /// Returns from &Digit to u32
+    #[inline]
     fn deref(&self) -> &u32 {
-        use self::Digit::*;
-        match *self {
-            I => &1,
-            V => &5,
-            X => &10,
-            L => &50,
-            C => &100,
-            D => &500,
-            M => &1000,
-        }
+        &BASE_VALUES[*self as usize]
     }
 }
@@ -1,6 +1,7 @@
+use core::fmt;
+use core::result;
+#[cfg(feature = "std")]
 use std::error;
-use std::fmt;
-use std::result;
 
 pub type Result<T> = result::Result<T, Error>;
 
@@ -9,32 +10,111 @@ pub type Result<T> = result::Result<T, Error>;
 pub enum Error {
     /// A char that is not a valid roman numeral
     InvalidDigit(char),
+    /// A char that is not a valid roman numeral, at the given byte offset of the parsed string
+    InvalidDigitAt { ch: char, offset: usize },
     /// A number that cannot be converted to a single Roman Numeral
     InvalidNumber(u32),
     /// Value is out of range
     OutOfRange(u32),
+    /// A digit sequence breaks the canonical form rules at the given position
+    NonCanonical(usize),
+    /// The input contains no digits
+    EmptyInput,
+    /// A value does not fit in the requested type
+    Overflow,
+    /// A string that should hold exactly one digit holds several
+    NotSingleDigit,
+    /// An output buffer is too small, holding the length that is needed
+    BufferTooSmall(usize),
+    /// Upper and lowercase digits are mixed, at the given position, in strict case parsing
+    MixedCase(usize),
+    /// Reading the input failed
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
 }
 
+/// Human readable error messages
+///
+/// # Examples
+/// ```rust
+/// # use septem::*;
+///
+/// assert_eq!(
+///     "0 is not a valid Roman numeral (must be positive)",
+///     Error::InvalidNumber(0).to_string()
+/// );
+/// assert_eq!("'A' is not a valid Roman digit", Error::InvalidDigit('A').to_string());
+/// ```
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::Error::*;
 
         match *self {
-            InvalidDigit(digit) => write!(f, "{}: {}", self, digit),
-            InvalidNumber(number) => write!(f, "{}: {}", self, number),
-            OutOfRange(value) => write!(f, "{}: {}", self, value),
+            InvalidDigit(digit) => write!(f, "'{}' is not a valid Roman digit", digit),
+            InvalidDigitAt { ch, offset } => write!(
+                f,
+                "'{}' is not a valid Roman digit at offset {}",
+                ch, offset
+            ),
+            InvalidNumber(number) => write!(
+                f,
+                "{} is not a valid Roman numeral (must be positive)",
+                number
+            ),
+            OutOfRange(value) => write!(f, "{} is out of range for a Roman numeral", value),
+            NonCanonical(position) => write!(
+                f,
+                "Roman numeral is not in canonical form at position {}",
+                position
+            ),
+            EmptyInput => f.write_str("cannot parse an empty Roman numeral"),
+            Overflow => f.write_str("Roman numeral value does not fit in the requested type"),
+            NotSingleDigit => f.write_str("expected a single Roman digit"),
+            BufferTooSmall(needed) => write!(
+                f,
+                "buffer is too small for the Roman numeral, {} bytes are needed",
+                needed
+            ),
+            MixedCase(position) => write!(
+                f,
+                "Roman numeral mixes upper and lowercase digits at position {}",
+                position
+            ),
+            #[cfg(feature = "std")]
+            Io(ref err) => write!(f, "failed to read Roman numeral: {}", err),
         }
     }
 }
 
-impl error::Error for Error {
-    fn description(&self) -> &str {
-        use self::Error::*;
+impl Error {
+    /// Moves the offset of an `InvalidDigitAt` or `MixedCase` error, for parsers working on part
+    /// of a string
+    pub(crate) fn shift_offset(self, by: usize) -> Error {
+        match self {
+            Error::InvalidDigitAt { ch, offset } => Error::InvalidDigitAt {
+                ch,
+                offset: offset + by,
+            },
+            Error::MixedCase(position) => Error::MixedCase(position + by),
+            err => err,
+        }
+    }
+}
 
+#[cfg(feature = "std")]
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match *self {
-            InvalidDigit(_) => "Encountered an invalid digit",
-            InvalidNumber(_) => "Cannot convert number to single roman digit",
-            OutOfRange(_) => "Roman numeral is out of range",
+            #[cfg(feature = "std")]
+            Error::Io(ref err) => Some(err),
+            _ => None,
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
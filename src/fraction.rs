@@ -0,0 +1,91 @@
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::str;
+
+use crate::{Error, Result};
+
+/// Dot marking one twelfth, an *uncia*
+pub const UNCIA: char = '·';
+
+/// A Roman fraction counted in twelfths, written with `S` for a half and a dot for each twelfth
+///
+/// Only proper fractions from 1/12 to 11/12 can be written, so 8/12 is `S··` and 5/12 is
+/// `·····`. Parsing accepts `S` in either case, at most once, followed by up to five dots.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let f: Fraction = "S··".parse().unwrap();
+/// assert_eq!(8, f.twelfths());
+/// assert_eq!("S··", f.to_string());
+///
+/// let f = Fraction::from_twelfths(3).unwrap();
+/// assert_eq!(0.25, f.value());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Fraction(u8);
+
+impl Fraction {
+    /// Creates a fraction of `n` twelfths. Requires `n` to be greater than 0, and less than 12.
+    ///
+    /// Returns `Fraction` , or an `septem::Error`
+    pub fn from_twelfths(n: u8) -> Result<Self> {
+        if n == 0 || n >= 12 {
+            return Err(Error::OutOfRange(n.into()));
+        }
+        Ok(Fraction(n))
+    }
+
+    /// Returns the number of twelfths in the fraction
+    pub fn twelfths(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the value of the fraction as a float
+    pub fn value(self) -> f64 {
+        f64::from(self.0) / 12.0
+    }
+}
+
+impl str::FromStr for Fraction {
+    type Err = Error;
+
+    /// Creates a fraction from an optional `S` followed by uncia dots
+    ///
+    /// Returns `Fraction` , or an `septem::Error`
+    fn from_str(s: &str) -> core::result::Result<Self, Error> {
+        let mut chars = s.chars().peekable();
+        let mut twelfths = 0;
+        if let Some('S') | Some('s') = chars.peek() {
+            chars.next();
+            twelfths += 6;
+        }
+        for c in chars {
+            if c != UNCIA {
+                return Err(Error::InvalidDigit(c));
+            }
+            twelfths += 1;
+            if twelfths % 6 == 0 {
+                return Err(Error::InvalidDigit(c));
+            }
+        }
+
+        if twelfths == 0 {
+            return Err(Error::EmptyInput);
+        }
+        Fraction::from_twelfths(twelfths)
+    }
+}
+
+impl Display for Fraction {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        if self.0 >= 6 {
+            f.write_str("S")?;
+        }
+        for _ in 0..self.0 % 6 {
+            write!(f, "{}", UNCIA)?;
+        }
+        Ok(())
+    }
+}
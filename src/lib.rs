@@ -25,17 +25,71 @@
 //! let roman = Roman::from_str("dxxxii").unwrap();
 //! assert_eq!(532, *roman);
 //! ```
+//!
+//! The default `std` feature can be turned off to use the crate in `no_std` environments, which
+//! only requires the `alloc` crate.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
+#[cfg(feature = "archaic")]
+mod apostrophus;
 mod digit;
 mod errors;
+#[cfg(feature = "fractions")]
+mod fraction;
+mod macros;
+mod parser;
+mod range;
 mod roman;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod vinculum;
 
 pub mod prelude {
-    pub use std::str::FromStr;
+    pub use core::str::FromStr;
 }
 
 pub use crate::{
-    digit::Digit,
+    digit::{Digit, DigitSeq, Digits, Style, VALUE_TABLE},
     errors::{Error, Result},
-    roman::Roman,
+    parser::{Parser, RomanParser, DEFAULT_DECORATIONS, DEFAULT_SEPARATORS},
+    range::{range, RomanRange},
+    roman::{Case, Roman, SMALL},
+    vinculum::{Vinculum, OVERLINE},
 };
+
+#[cfg(feature = "archaic")]
+pub use crate::apostrophus::REVERSED_C;
+#[cfg(feature = "smallvec")]
+pub use crate::digit::InlineDigits;
+#[cfg(feature = "fractions")]
+pub use crate::fraction::{Fraction, UNCIA};
+#[cfg(feature = "serde")]
+pub use crate::serde_impl::as_value;
+
+/// Checks whether a string is a well-formed Roman numeral, in canonical form and representable,
+/// i.e. within `1..=Digit::MAX`, or `1..=Digit::MAX_ARCHAIC` with the `archaic` feature.
+///
+/// # Examples
+/// ```rust
+/// assert!(septem::is_valid("MCMXCIV"));
+/// assert!(septem::is_valid("xiv"));
+/// assert!(!septem::is_valid("IIII"));
+/// assert!(!septem::is_valid("XIVA"));
+/// assert!(!septem::is_valid(""));
+///
+/// # #[cfg(feature = "archaic")]
+/// assert!(septem::is_valid("ↁ"));
+/// ```
+pub fn is_valid(s: &str) -> bool {
+    let mut digits = alloc::vec::Vec::with_capacity(s.len());
+    for c in s.chars() {
+        match Digit::from_char(c) {
+            Ok(d) => digits.extend(d),
+            Err(_) => return false,
+        }
+    }
+    Digit::validate(&digits).is_ok() && Digit::is_representable(Digit::value_of(&digits))
+}
@@ -0,0 +1,31 @@
+/// Creates a `Roman` from a string literal, checked at compile time
+///
+/// The literal must be a canonical numeral, as accepted by `Digit::validate`, written with the
+/// ASCII letters `I V X L C D M` in either case. Anything else fails to compile. The macro can be
+/// used in `const` contexts.
+///
+/// # Examples
+/// ```rust
+/// # use septem::*;
+///
+/// const YEAR: Roman = septem::roman!("MMXXIV");
+/// assert_eq!(2024, *YEAR);
+/// assert_eq!(14, *septem::roman!("xiv"));
+/// ```
+///
+/// A typo is a compile error:
+/// ```compile_fail
+/// let year = septem::roman!("MMXZIV");
+/// ```
+///
+/// So is a non-canonical numeral:
+/// ```compile_fail
+/// let four = septem::roman!("IIII");
+/// ```
+#[macro_export]
+macro_rules! roman {
+    ($s:literal) => {{
+        const ROMAN: $crate::Roman = $crate::Roman::from_literal($s);
+        ROMAN
+    }};
+}
@@ -0,0 +1,291 @@
+use alloc::string::String;
+use core::iter::FusedIterator;
+use core::str::CharIndices;
+
+use crate::{Digit, DigitSeq, Error, Result, Roman};
+
+/// Decorations commonly written around numerals in bibliographic data, e.g. `"No. XIV"` or
+/// `"XIV."`
+pub const DEFAULT_DECORATIONS: &[&str] = &["No.", "Nr.", "§", "."];
+
+/// Separators commonly used to visually group the digits of a numeral, e.g. `"M·CM·XC·IV"`
+pub const DEFAULT_SEPARATORS: &[char] = &[' ', '·', '.'];
+
+/// A configurable parser for Roman numerals embedded in surrounding text
+///
+/// With no options set it behaves exactly like `Roman::from_str`.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let parser = Parser::new().strip_decorations(DEFAULT_DECORATIONS);
+/// assert_eq!(14, *parser.parse("No. XIV").unwrap());
+/// assert_eq!(14, *parser.parse("xiv.").unwrap());
+/// assert_eq!(7, *parser.parse("§ VII").unwrap());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Parser<'d> {
+    decorations: &'d [&'d str],
+    separators: &'d [char],
+    strict_case: bool,
+}
+
+impl<'d> Parser<'d> {
+    /// Creates a parser with no options set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Strips any of the given decorations, and surrounding whitespace, from the start and end of
+    /// the input before parsing the numeral. Decorations are matched ignoring ASCII case.
+    pub fn strip_decorations(mut self, decorations: &'d [&'d str]) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
+    /// Ignores any of the given separator characters anywhere in the numeral, so digit groups can
+    /// be visually separated.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let parser = Parser::new().separators(DEFAULT_SEPARATORS);
+    /// assert_eq!(1994, *parser.parse("M·CM·XC·IV").unwrap());
+    /// assert_eq!(1994, *parser.parse(" M CM  XC IV ").unwrap());
+    /// assert!("M·CM·XC·IV".parse::<Roman>().is_err());
+    /// ```
+    pub fn separators(mut self, separators: &'d [char]) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Requires the numeral to be written in a single case, so `mcmxciv` and `MCMXCIV` parse but
+    /// `McMxCiV` is rejected with `septem::Error::MixedCase`. Digits without a case, such as the
+    /// archaic ones, are accepted in either.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let parser = Parser::new().strict_case();
+    /// assert_eq!(1994, *parser.parse("mcmxciv").unwrap());
+    ///
+    /// match parser.parse("McMxCiV") {
+    ///     Err(Error::MixedCase(position)) => assert_eq!(1, position),
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    pub fn strict_case(mut self) -> Self {
+        self.strict_case = true;
+        self
+    }
+
+    /// Parses a Roman numeral, stripping decorations if configured
+    ///
+    /// Returns `Roman` , or an `septem::Error`
+    pub fn parse(&self, s: &str) -> Result<Roman> {
+        self.parse_decorated(s).map(|(roman, _, _)| roman)
+    }
+
+    /// Parses a Roman numeral like `Parser::parse`, also returning the stripped leading and
+    /// trailing text
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let parser = Parser::new().strip_decorations(DEFAULT_DECORATIONS);
+    /// let (roman, leading, trailing) = parser.parse_decorated("No. XIV.").unwrap();
+    /// assert_eq!(14, *roman);
+    /// assert_eq!("No. ", leading);
+    /// assert_eq!(".", trailing);
+    /// ```
+    ///
+    /// Returns `(Roman, &str, &str)` , or an `septem::Error`
+    pub fn parse_decorated<'a>(&self, s: &'a str) -> Result<(Roman, &'a str, &'a str)> {
+        let (start, end) = self.core_bounds(s);
+        if self.strict_case {
+            check_case(&s[start..end]).map_err(|err| err.shift_offset(start))?;
+        }
+        let core = &s[start..end];
+        let roman = if self.separators.is_empty() {
+            core.parse::<Roman>()
+        } else {
+            core.chars()
+                .filter(|c| !self.separators.contains(c))
+                .collect::<String>()
+                .parse::<Roman>()
+                .map_err(|err| match err {
+                    Error::InvalidDigitAt { ch, offset } => Error::InvalidDigitAt {
+                        ch,
+                        offset: self.unfiltered_offset(core, offset),
+                    },
+                    err => err,
+                })
+        };
+        Ok((
+            roman.map_err(|err| err.shift_offset(start))?,
+            &s[..start],
+            &s[end..],
+        ))
+    }
+
+    /// Maps a byte offset in `core` with the separators removed back to an offset in `core`
+    fn unfiltered_offset(&self, core: &str, offset: usize) -> usize {
+        let mut filtered = 0;
+        for (i, c) in core.char_indices() {
+            if self.separators.contains(&c) {
+                continue;
+            }
+            if filtered == offset {
+                return i;
+            }
+            filtered += c.len_utf8();
+        }
+        core.len()
+    }
+
+    /// Finds the byte range of the input left after stripping decorations
+    fn core_bounds(&self, s: &str) -> (usize, usize) {
+        if self.decorations.is_empty() {
+            return (0, s.len());
+        }
+
+        let mut core = s.trim();
+        'leading: loop {
+            for decoration in self.decorations {
+                if let Some(rest) = strip_prefix_ignore_case(core, decoration) {
+                    core = rest.trim_start();
+                    continue 'leading;
+                }
+            }
+            break;
+        }
+        'trailing: loop {
+            for decoration in self.decorations {
+                if let Some(rest) = strip_suffix_ignore_case(core, decoration) {
+                    core = rest.trim_end();
+                    continue 'trailing;
+                }
+            }
+            break;
+        }
+
+        let start = core.as_ptr() as usize - s.as_ptr() as usize;
+        (start, start + core.len())
+    }
+}
+
+/// An iterator over the digits of a numeral string, together with the byte offset of the
+/// character each digit was decoded from
+///
+/// Characters that stand for several digits, like `Ⅻ`, yield each digit with the same offset. An
+/// invalid character yields an `Err` and ends the iteration.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let mut digits = RomanParser::new("XⅣ?");
+/// assert_eq!((Digit::X, 0), digits.next().unwrap().unwrap());
+/// assert_eq!((Digit::I, 1), digits.next().unwrap().unwrap());
+/// assert_eq!((Digit::V, 1), digits.next().unwrap().unwrap());
+/// match digits.next() {
+///     Some(Err(Error::InvalidDigit(c))) => assert_eq!('?', c),
+///     _ => panic!(),
+/// }
+/// assert!(digits.next().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RomanParser<'a> {
+    chars: CharIndices<'a>,
+    pending: DigitSeq,
+    index: usize,
+    offset: usize,
+    failed: bool,
+}
+
+impl<'a> RomanParser<'a> {
+    /// Creates an iterator over the digits of the string
+    pub fn new(s: &'a str) -> Self {
+        RomanParser {
+            chars: s.char_indices(),
+            pending: DigitSeq::new(),
+            index: 0,
+            offset: 0,
+            failed: false,
+        }
+    }
+}
+
+impl<'a> Iterator for RomanParser<'a> {
+    type Item = Result<(Digit, usize)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(&digit) = self.pending.get(self.index) {
+                self.index += 1;
+                return Some(Ok((digit, self.offset)));
+            }
+            if self.failed {
+                return None;
+            }
+
+            let (offset, c) = self.chars.next()?;
+            match Digit::from_char(c) {
+                Ok(digits) => {
+                    self.pending = digits;
+                    self.index = 0;
+                    self.offset = offset;
+                }
+                Err(err) => {
+                    self.failed = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+impl<'a> FusedIterator for RomanParser<'a> {}
+
+/// Checks that all cased characters share the same case, returning the byte position of the
+/// first one that does not as an `Error::MixedCase`
+fn check_case(s: &str) -> Result<()> {
+    let mut upper = None;
+    for (position, c) in s.char_indices() {
+        let is_upper = match (c.is_uppercase(), c.is_lowercase()) {
+            (true, false) => true,
+            (false, true) => false,
+            _ => continue,
+        };
+        if *upper.get_or_insert(is_upper) != is_upper {
+            return Err(Error::MixedCase(position));
+        }
+    }
+    Ok(())
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    match s.get(..prefix.len()) {
+        Some(head) if !prefix.is_empty() && head.eq_ignore_ascii_case(prefix) => {
+            Some(&s[prefix.len()..])
+        }
+        _ => None,
+    }
+}
+
+fn strip_suffix_ignore_case<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    let split = s.len().checked_sub(suffix.len())?;
+    match s.get(split..) {
+        Some(tail) if !suffix.is_empty() && tail.eq_ignore_ascii_case(suffix) => Some(&s[..split]),
+        _ => None,
+    }
+}
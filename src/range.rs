@@ -0,0 +1,74 @@
+use core::iter::FusedIterator;
+use core::ops::{self, Bound, RangeBounds};
+
+use crate::{Error, Result, Roman};
+
+/// Creates a lazy iterator over the Roman numerals of a range of numbers, such as `1..=10` or
+/// `1..4`. Unbounded ends stop at `Roman::MIN` and `Roman::MAX`.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let outline: Vec<String> = septem::range(1..=4).unwrap().map(|r| format!("{:#}", r)).collect();
+/// assert_eq!(vec!["i", "ii", "iii", "iv"], outline);
+///
+/// assert_eq!(Some(Roman::MAX), septem::range(3990..).unwrap().last().map(|r| *r));
+///
+/// match septem::range(0..10) {
+///     Err(Error::OutOfRange(n)) => assert_eq!(0, n),
+///     _ => panic!(),
+/// }
+/// ```
+///
+/// Returns `RomanRange`, or an `septem::Error::OutOfRange` if the range starts at 0 or ends past
+/// `Roman::MAX`.
+pub fn range<R: RangeBounds<u32>>(range: R) -> Result<RomanRange> {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.checked_add(1).ok_or(Error::OutOfRange(n))?,
+        Bound::Unbounded => Roman::MIN,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1).ok_or(Error::OutOfRange(n))?,
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => Roman::MAX + 1,
+    };
+    if start < Roman::MIN {
+        return Err(Error::OutOfRange(start));
+    }
+    if end > Roman::MAX + 1 {
+        return Err(Error::OutOfRange(end - 1));
+    }
+
+    Ok(RomanRange { inner: start..end })
+}
+
+/// Iterator over the Roman numerals of a range of numbers, created by `septem::range`
+#[derive(Debug, Clone)]
+pub struct RomanRange {
+    inner: ops::Range<u32>,
+}
+
+impl Iterator for RomanRange {
+    type Item = Roman;
+
+    fn next(&mut self) -> Option<Roman> {
+        self.inner.next().map(Roman::from_unchecked)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for RomanRange {
+    fn next_back(&mut self) -> Option<Roman> {
+        self.inner.next_back().map(Roman::from_unchecked)
+    }
+}
+
+impl ExactSizeIterator for RomanRange {}
+
+impl FusedIterator for RomanRange {}
@@ -1,17 +1,115 @@
-use std::fmt::{Display, Formatter, Result as FmtResult};
-use std::{ops, str};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::{Alignment, Display, Formatter, Result as FmtResult, Write};
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+use core::{ops, str};
 
-use crate::{Digit, Error, Result};
+use crate::digit::{ADDITIVE_TABLE, FROM_INT, TABLE};
+use crate::{Digit, DigitSeq, Error, Result};
+
+/// Canonical numerals for 1 through 20, where `SMALL[n - 1]` is the numeral for `n`
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// assert_eq!("I", SMALL[0]);
+/// assert_eq!("XIV", SMALL[13]);
+/// assert_eq!("XX", SMALL[19]);
+/// ```
+pub const SMALL: [&str; 20] = [
+    "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII", "XIII", "XIV", "XV",
+    "XVI", "XVII", "XVIII", "XIX", "XX",
+];
+
+/// Numerals that have a single precomposed character in the Unicode Number Forms block, in
+/// uppercase (U+2160 block) and lowercase (U+2170 block)
+const NUMBER_FORMS: [(&str, char, char); 16] = [
+    ("I", 'Ⅰ', 'ⅰ'),
+    ("II", 'Ⅱ', 'ⅱ'),
+    ("III", 'Ⅲ', 'ⅲ'),
+    ("IV", 'Ⅳ', 'ⅳ'),
+    ("V", 'Ⅴ', 'ⅴ'),
+    ("VI", 'Ⅵ', 'ⅵ'),
+    ("VII", 'Ⅶ', 'ⅶ'),
+    ("VIII", 'Ⅷ', 'ⅷ'),
+    ("IX", 'Ⅸ', 'ⅸ'),
+    ("X", 'Ⅹ', 'ⅹ'),
+    ("XI", 'Ⅺ', 'ⅺ'),
+    ("XII", 'Ⅻ', 'ⅻ'),
+    ("L", 'Ⅼ', 'ⅼ'),
+    ("C", 'Ⅽ', 'ⅽ'),
+    ("D", 'Ⅾ', 'ⅾ'),
+    ("M", 'Ⅿ', 'ⅿ'),
+];
 
 /// A Roman number
 ///
-/// Stores the value internally a u32
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
-pub struct Roman(u32);
+/// Stores the value internally a u32, together with the notation used when rendering it.
+/// Comparisons and hashing only look at the value, so `IIII` and `IV` are equal.
+#[derive(Debug, Clone, Copy)]
+pub struct Roman(u32, Notation);
+
+/// How a Roman numeral is rendered into digits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Notation {
+    /// Shortest canonical form, using subtractive pairs such as `IV`
+    Subtractive,
+    /// Longest form, using only additive runs such as `IIII`
+    Additive,
+}
+
+/// Letter case used when rendering a Roman numeral
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `MCMXCIV`
+    Upper,
+    /// `mcmxciv`
+    Lower,
+}
 
 impl Roman {
+    /// The largest value of a Roman numeral, `Digit::MAX`, or `Digit::MAX_ARCHAIC` with the
+    /// `archaic` feature, where the large values are written with the archaic digits. Vinculum
+    /// notation goes further with `Vinculum::MAX`.
+    #[cfg(not(feature = "archaic"))]
+    pub const MAX: u32 = Digit::MAX;
+    /// The largest value of a Roman numeral, `Digit::MAX`, or `Digit::MAX_ARCHAIC` with the
+    /// `archaic` feature, where the large values are written with the archaic digits. Vinculum
+    /// notation goes further with `Vinculum::MAX`.
+    #[cfg(feature = "archaic")]
+    pub const MAX: u32 = Digit::MAX_ARCHAIC;
+
+    /// The smallest value of a Roman numeral, as there is no numeral for zero
+    pub const MIN: u32 = 1;
+
+    /// Returns the largest Roman numeral, with the value `Roman::MAX`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!(Roman::MAX, *Roman::max_value());
+    /// # #[cfg(not(feature = "archaic"))]
+    /// assert_eq!("MMMCMXCIX", Roman::max_value().to_string());
+    /// ```
+    pub const fn max_value() -> Roman {
+        Roman(Roman::MAX, Notation::Subtractive)
+    }
+
+    /// Returns the smallest Roman numeral, `I`
+    pub const fn min_value() -> Roman {
+        Roman(Roman::MIN, Notation::Subtractive)
+    }
+
     /// Creates a Roman numeral for any value that implements `Into<u32>`. Requires value to be
-    /// greater than 0, and less than 4000.
+    /// in `Roman::MIN..=Roman::MAX`.
     ///
     /// # Examples
     /// ```rust
@@ -25,10 +123,10 @@ impl Roman {
     /// Returns `Roman` , or an `septem::Error
     pub fn from<T: Into<u32>>(val: T) -> Result<Self> {
         let val = val.into();
-        if val == 0 || val > 3999 {
+        if !(Roman::MIN..=Roman::MAX).contains(&val) {
             return Err(Error::OutOfRange(val));
         }
-        Ok(Roman(val))
+        Ok(Roman(val, Notation::Subtractive))
     }
 
     /// Creates a Roman numeral for any value that implements `Into<u32>`. Does not to any range
@@ -39,13 +137,403 @@ impl Roman {
     /// # use septem::prelude::*;
     /// # use septem::*;
     ///
+    /// # #[cfg(not(feature = "archaic"))]
+    /// # {
     /// let sept: Roman = Roman::from_unchecked(5032u32);
     /// assert_eq!("MMMMMXXXII", sept.to_string());
+    /// # }
     /// ```
     ///
     /// Returns `Roman`
     pub fn from_unchecked<T: Into<u32>>(val: T) -> Self {
-        Roman(val.into())
+        Roman(val.into(), Notation::Subtractive)
+    }
+
+    /// Parses and validates an ASCII numeral in a const context, panicking on invalid input.
+    /// Backs the `roman!` macro, where the panic becomes a compile error.
+    #[doc(hidden)]
+    pub const fn from_literal(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        if bytes.is_empty() {
+            panic!("empty roman numeral");
+        }
+
+        let mut val = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            let curr = literal_value(bytes[i]);
+            if i + 1 < bytes.len() && curr < literal_value(bytes[i + 1]) {
+                val += literal_value(bytes[i + 1]) - curr;
+                i += 2;
+            } else {
+                val += curr;
+                i += 1;
+            }
+        }
+        if val > Digit::MAX {
+            panic!("roman numeral is out of range");
+        }
+
+        // The numeral is canonical if it is exactly the decomposition of its own value
+        let mut num = val;
+        let mut pos = 0;
+        let mut t = 0;
+        while t < TABLE.len() {
+            let (value, digits) = TABLE[t];
+            while num >= value {
+                let mut k = 0;
+                while k < digits.len() {
+                    if pos >= bytes.len() || literal_value(bytes[pos]) != digits[k].value_const() {
+                        panic!("roman numeral is not in canonical form");
+                    }
+                    pos += 1;
+                    k += 1;
+                }
+                num -= value;
+            }
+            t += 1;
+        }
+        if pos != bytes.len() {
+            panic!("roman numeral is not in canonical form");
+        }
+
+        Roman(val, Notation::Subtractive)
+    }
+
+    /// Reads a single Roman numeral from a byte source until EOF, without buffering the whole
+    /// input. Only ASCII letters are accepted, and surrounding ASCII whitespace such as a trailing
+    /// newline is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let r = Roman::from_reader(&b"MCMXCIV\n"[..]).unwrap();
+    /// assert_eq!(1994, *r);
+    /// ```
+    ///
+    /// Returns `Roman` , or an `septem::Error`, wrapping any I/O error in `Error::Io`. Stops reading
+    /// with `Error::Overflow` as soon as the total passes `Roman::MAX`, so an endless source of
+    /// digits does not keep it reading.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: std::io::Read>(r: R) -> Result<Self> {
+        use std::io::Read;
+
+        let mut acc = Accumulator::default();
+        let (mut started, mut ended) = (false, false);
+        for byte in std::io::BufReader::new(r).bytes() {
+            let byte = byte?;
+            if byte.is_ascii_whitespace() {
+                ended = started;
+                continue;
+            }
+            if ended {
+                return Err(Error::InvalidDigit(byte.into()));
+            }
+            acc.push(Digit::from_byte(byte)?)?;
+            started = true;
+        }
+        acc.finish()
+    }
+
+    /// Creates a Roman numeral from a sequence of digits, requiring the digits to be in canonical
+    /// form as checked by `Digit::validate`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let xiv = Roman::try_from_digit_iter(vec![Digit::X, Digit::I, Digit::V]).unwrap();
+    /// assert_eq!(14, *xiv);
+    ///
+    /// match Roman::try_from_digit_iter(vec![Digit::X, Digit::I, Digit::I, Digit::I, Digit::I]) {
+    ///     Err(Error::NonCanonical(position)) => assert_eq!(4, position),
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    ///
+    /// Returns `Roman` , or an `septem::Error` reporting the first non-canonical position
+    pub fn try_from_digit_iter<I: IntoIterator<Item = Digit>>(iter: I) -> Result<Self> {
+        let digits: Vec<Digit> = iter.into_iter().collect();
+        Digit::validate(&digits)?;
+        Roman::from(Digit::value_of::<u32>(&digits))
+    }
+
+    /// Parses a strictly classical Roman numeral, for input that must be exactly one of the
+    /// numerals from 1 to 3999. The accepted grammar is
+    ///
+    /// ```text
+    /// M{0,3} (CM | CD | D? C{0,3}) (XC | XL | L? X{0,3}) (IX | IV | V? I{0,3})
+    /// ```
+    ///
+    /// matching at least one digit, written with the seven ASCII letters `IVXLCDM` in upper or
+    /// lowercase. Unicode numerals, archaic digits, vinculum, `N` for *nulla*, whitespace and
+    /// any other character are rejected, whatever features are enabled.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!(1994, *Roman::parse_classical("MCMXCIV").unwrap());
+    ///
+    /// match Roman::parse_classical("MMMM") {
+    ///     Err(Error::Overflow) => {}
+    ///     _ => panic!(),
+    /// }
+    /// match Roman::parse_classical("Ⅻ") {
+    ///     Err(Error::InvalidDigit(c)) => assert_eq!('Ⅻ', c),
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    ///
+    /// Returns `Roman`, or an `septem::Error::InvalidDigit` for the first char outside `IVXLCDM`,
+    /// `septem::Error::Overflow` for values of 4000 and above, `septem::Error::NonCanonical` for
+    /// numerals outside the grammar and `septem::Error::EmptyInput` for an empty string.
+    pub fn parse_classical(s: &str) -> Result<Self> {
+        let mut digits = Vec::with_capacity(s.len());
+        for c in s.chars() {
+            let digit = if c.is_ascii() {
+                Digit::from_byte(c as u8)
+            } else {
+                Err(Error::InvalidDigit(c))
+            };
+            match digit {
+                #[cfg(feature = "nulla")]
+                Ok(Digit::Nulla) => return Err(Error::InvalidDigit(c)),
+                Ok(digit) => digits.push(digit),
+                Err(_) => return Err(Error::InvalidDigit(c)),
+            }
+        }
+        if digits.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+
+        let value = Digit::checked_value_of::<u32>(&digits)?;
+        if value > Digit::MAX {
+            return Err(Error::Overflow);
+        }
+        Digit::validate(&digits)?;
+        Ok(Roman(value, Notation::Subtractive))
+    }
+
+    /// Returns the subtractive pairs present in the Roman numeral, in order
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let r = Roman::from(1994u32).unwrap();
+    /// assert_eq!(
+    ///     vec![(Digit::C, Digit::M), (Digit::X, Digit::C), (Digit::I, Digit::V)],
+    ///     r.subtractive_pairs()
+    /// );
+    /// ```
+    pub fn subtractive_pairs(&self) -> Vec<(Digit, Digit)> {
+        let digits = self.to_digits();
+        let mut pairs = vec![];
+        let mut i = 0;
+        while i + 1 < digits.len() {
+            if Digit::is_subtractive_pair(digits[i], digits[i + 1]) {
+                pairs.push((digits[i], digits[i + 1]));
+                i += 2;
+            } else {
+                i += 1;
+            }
+        }
+        pairs
+    }
+
+    /// Returns the value of the Roman numeral as any type that implements `From<u32>`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let r: Roman = "MCMXCIV".parse().unwrap();
+    /// assert_eq!(1994u32, r.value());
+    /// assert_eq!(1994u64, r.value::<u64>());
+    /// ```
+    #[inline]
+    pub fn value<T>(&self) -> T
+    where
+        T: From<u32>,
+    {
+        T::from(self.0)
+    }
+
+    /// Returns the value of the Roman numeral, for use as an explicit sort or hash key
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let mut numerals: Vec<Roman> = vec!["x", "ii", "v"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse().unwrap())
+    ///     .collect();
+    /// numerals.sort_by_key(Roman::as_sort_key);
+    /// assert_eq!(vec![2, 5, 10], numerals.iter().map(Roman::as_sort_key).collect::<Vec<_>>());
+    /// ```
+    pub fn as_sort_key(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the length in bytes of the rendered Roman numeral
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!(7, Roman::from(1994u32).unwrap().len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.to_digits()
+            .into_iter()
+            .map(|d| d.to_uppercase().len_utf8())
+            .sum()
+    }
+
+    /// Returns `true` if the numeral renders as an empty string, which only happens for an
+    /// unchecked zero
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Appends the uppercase representation of the Roman numeral to a `String`, reserving the
+    /// needed space up front
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let mut buf = String::from("Chapter ");
+    /// Roman::from(14u32).unwrap().append_to(&mut buf);
+    /// assert_eq!("Chapter XIV", buf);
+    /// ```
+    pub fn append_to(&self, buf: &mut String) {
+        self.append_with(buf, Digit::to_uppercase);
+    }
+
+    /// Appends the lowercase representation of the Roman numeral to a `String`, reserving the
+    /// needed space up front
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let mut buf = String::from("page ");
+    /// Roman::from(14u32).unwrap().append_lowercase_to(&mut buf);
+    /// assert_eq!("page xiv", buf);
+    /// ```
+    pub fn append_lowercase_to(&self, buf: &mut String) {
+        self.append_with(buf, Digit::to_lowercase);
+    }
+
+    fn append_with(&self, buf: &mut String, render: fn(Digit) -> char) {
+        let digits = self.to_digits();
+        buf.reserve(digits.iter().map(|&d| render(d).len_utf8()).sum());
+        buf.extend(digits.into_iter().map(render));
+    }
+
+    /// Adds two Roman numerals, returning `None` if the sum is larger than `Roman::MAX`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let a = Roman::from(2000u32).unwrap();
+    /// let b = Roman::from(1999u32).unwrap();
+    /// assert_eq!("MMMCMXCIX", a.checked_add(b).unwrap().to_string());
+    /// assert_eq!(None, Roman::max_value().checked_add(a));
+    /// ```
+    pub fn checked_add(self, rhs: Roman) -> Option<Roman> {
+        self.0
+            .checked_add(rhs.0)
+            .and_then(|val| Roman::from(val).ok())
+    }
+
+    /// Multiplies two Roman numerals by value, returning `None` if the product is larger than
+    /// `Roman::MAX`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let xii = Roman::from(12u32).unwrap();
+    /// assert_eq!("CXLIV", xii.checked_mul(xii).unwrap().to_string());
+    /// assert_eq!(None, Roman::max_value().checked_mul(Roman::from(2u32).unwrap()));
+    /// ```
+    pub fn checked_mul(self, rhs: Roman) -> Option<Roman> {
+        self.0
+            .checked_mul(rhs.0)
+            .and_then(|val| Roman::from(val).ok())
+    }
+
+    /// Subtracts two Roman numerals, returning `None` if the result would not be positive
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let v = Roman::from(5u32).unwrap();
+    /// let x = Roman::from(10u32).unwrap();
+    /// assert_eq!(Some(v), x.checked_sub(v));
+    /// assert_eq!(None, v.checked_sub(x));
+    /// assert_eq!(None, v.checked_sub(v));
+    /// ```
+    pub fn checked_sub(self, rhs: Roman) -> Option<Roman> {
+        self.0
+            .checked_sub(rhs.0)
+            .and_then(|val| Roman::from(val).ok())
+    }
+
+    /// Adds up Roman numerals, checking that the total is still a valid numeral
+    ///
+    /// There is no `Sum` implementation because an empty sum or a total above `Roman::MAX` has
+    /// no numeral.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let pages = vec![Roman::from(12u32).unwrap(), Roman::from(30u32).unwrap()];
+    /// assert_eq!("XLII", Roman::try_sum(pages).unwrap().to_string());
+    ///
+    /// match Roman::try_sum(vec![]) {
+    ///     Err(Error::EmptyInput) => {}
+    ///     _ => panic!(),
+    /// }
+    ///
+    /// match Roman::try_sum(vec![Roman::max_value(), Roman::min_value()]) {
+    ///     Err(Error::Overflow) => {}
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    ///
+    /// Returns `Roman`, or an `septem::Error::EmptyInput` if there is nothing to add, or an
+    /// `septem::Error::Overflow` if the total is larger than `Roman::MAX`.
+    pub fn try_sum<I: IntoIterator<Item = Roman>>(iter: I) -> Result<Roman> {
+        let mut iter = iter.into_iter();
+        let first = iter.next().ok_or(Error::EmptyInput)?;
+        let total = iter.try_fold(first.0, |acc, r| {
+            acc.checked_add(r.0)
+                .filter(|&total| total <= Roman::MAX)
+                .ok_or(Error::Overflow)
+        })?;
+        Roman::from(total)
     }
 
     /// Returns lowercase string representation of the Roman numeral
@@ -64,47 +552,338 @@ impl Roman {
             .collect()
     }
 
-    /// Returns vector of digits representing the roman numeral
-    pub fn to_digits(self) -> Vec<Digit> {
-        enum Pair {
-            Single(Digit),
-            Double((Digit, Digit)),
+    /// Returns the Roman numeral as a single precomposed Unicode character where one exists
+    ///
+    /// Only 1 through 12, 50, 100, 500 and 1000 have a precomposed form (`Ⅰ` to `Ⅻ`, `Ⅼ`, `Ⅽ`,
+    /// `Ⅾ` and `Ⅿ`), and only when rendered as such, so an expanded `IIII` stays ASCII. Every other
+    /// numeral falls back to uppercase ASCII letters.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!("Ⅻ", Roman::from(12u32).unwrap().to_unicode());
+    /// assert_eq!("XIII", Roman::from(13u32).unwrap().to_unicode());
+    /// assert_eq!("Ⅿ", Roman::from(1000u32).unwrap().to_unicode());
+    /// ```
+    pub fn to_unicode(self) -> String {
+        self.to_number_form(Case::Upper)
+    }
+
+    /// Returns the Roman numeral as a single precomposed lowercase Unicode character where one
+    /// exists, see `Roman::to_unicode`. Every other numeral falls back to lowercase ASCII letters.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!("ⅻ", Roman::from(12u32).unwrap().to_unicode_lowercase());
+    /// assert_eq!("xiii", Roman::from(13u32).unwrap().to_unicode_lowercase());
+    /// ```
+    pub fn to_unicode_lowercase(self) -> String {
+        self.to_number_form(Case::Lower)
+    }
+
+    fn to_number_form(self, case: Case) -> String {
+        let ascii = self.to_uppercase();
+        match NUMBER_FORMS.iter().find(|&&(s, _, _)| s == ascii) {
+            Some(&(_, upper, _)) if case == Case::Upper => upper.to_string(),
+            Some(&(_, _, lower)) => lower.to_string(),
+            None => self.to_string_case(case),
         }
-        let val = self.0;
-        if val == 0 {
-            return vec![];
+    }
+
+    /// Returns the string representation of the Roman numeral in the given case
+    ///
+    /// Archaic digits have no lowercase form and are rendered unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let r = Roman::from(1994u32).unwrap();
+    /// assert_eq!("mcmxciv", r.to_string_case(Case::Lower));
+    /// assert_eq!("MCMXCIV", r.to_string_case(Case::Upper));
+    /// ```
+    pub fn to_string_case(self, case: Case) -> String {
+        match case {
+            Case::Upper => self.to_uppercase(),
+            Case::Lower => self.to_lowercase(),
         }
-        let mut num = val;
-        let pairs = [
-            (1000, Pair::Single(Digit::M)),
-            (900, Pair::Double((Digit::C, Digit::M))),
-            (500, Pair::Single(Digit::D)),
-            (400, Pair::Double((Digit::C, Digit::D))),
-            (100, Pair::Single(Digit::C)),
-            (90, Pair::Double((Digit::X, Digit::C))),
-            (50, Pair::Single(Digit::L)),
-            (40, Pair::Double((Digit::X, Digit::L))),
-            (10, Pair::Single(Digit::X)),
-            (9, Pair::Double((Digit::I, Digit::X))),
-            (5, Pair::Single(Digit::V)),
-            (4, Pair::Double((Digit::I, Digit::V))),
-            (1, Pair::Single(Digit::I)),
-        ];
-
-        let mut acc = vec![];
-        for (val, pair) in pairs.iter() {
-            while num >= *val {
-                match pair {
-                    Pair::Single(s) => acc.push(*s),
-                    Pair::Double((a, b)) => {
-                        acc.push(*a);
-                        acc.push(*b);
-                    }
-                }
-                num -= val;
+    }
+
+    /// Returns the same Roman numeral rendered in its shortest canonical form, using subtractive
+    /// pairs
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let r = Roman::from(1994u32).unwrap().expand().minify();
+    /// assert_eq!("MCMXCIV", r.to_string());
+    /// ```
+    pub fn minify(&self) -> Roman {
+        Roman(self.0, Notation::Subtractive)
+    }
+
+    /// Returns the same Roman numeral rendered in its longest form, using only additive runs
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let r = Roman::from(1994u32).unwrap().expand();
+    /// assert_eq!("MDCCCCLXXXXIIII", r.to_string());
+    /// assert_eq!(1994, *r);
+    /// ```
+    pub fn expand(&self) -> Roman {
+        Roman(self.0, Notation::Additive)
+    }
+
+    /// Returns each rendered group of the Roman numeral together with the value it contributes.
+    /// The values always sum to the value of the numeral.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let r = Roman::from(1994u32).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         ("M".to_string(), 1000),
+    ///         ("CM".to_string(), 900),
+    ///         ("XC".to_string(), 90),
+    ///         ("IV".to_string(), 4),
+    ///     ],
+    ///     r.breakdown()
+    /// );
+    /// ```
+    pub fn breakdown(&self) -> Vec<(String, u32)> {
+        let mut num = self.0;
+        let mut groups = vec![];
+        for &(value, digits) in self.table() {
+            let count = num / value;
+            if count > 0 {
+                let group: String = digits.iter().map(|d| d.to_uppercase()).collect();
+                groups.push((group.repeat(count as usize), value * count));
+                num %= value;
             }
         }
-        acc
+        groups
+    }
+
+    /// Returns vector of digits representing the roman numeral
+    ///
+    /// A `Roman` only stores its value, so the digits are derived again on each call rather than
+    /// borrowed, which is why there is no `AsRef<[Digit]>` or `Index` implementation.
+    pub fn to_digits(self) -> DigitSeq {
+        Digit::decompose(self.0, self.table())
+    }
+
+    /// Returns the digits representing the roman numeral like `Roman::to_digits`, but stored
+    /// inline in an `InlineDigits` instead of allocating a `Vec`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let r = Roman::from(1994u32).unwrap();
+    /// assert_eq!(r.to_digits()[..], r.to_digits_inline()[..]);
+    /// assert_eq!(r.expand().to_digits()[..], r.expand().to_digits_inline()[..]);
+    /// ```
+    #[cfg(feature = "smallvec")]
+    pub fn to_digits_inline(self) -> crate::InlineDigits {
+        let mut digits = crate::InlineDigits::new();
+        Digit::decompose_into(self.0, self.table(), &mut digits);
+        digits
+    }
+
+    /// Returns the digit at the given position of the numeral, in the notation it is rendered in
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// let r = Roman::from(1994u32).unwrap();
+    /// assert_eq!(Some(Digit::M), r.digit(0));
+    /// assert_eq!(Some(Digit::V), r.digit(6));
+    /// assert_eq!(None, r.digit(7));
+    /// assert_eq!(Some(Digit::D), r.expand().digit(1));
+    /// ```
+    pub fn digit(&self, index: usize) -> Option<Digit> {
+        self.to_digits().get(index).copied()
+    }
+
+    /// Returns the value to digits table for the notation of the numeral
+    fn table(&self) -> &'static [(u32, &'static [Digit])] {
+        match self.1 {
+            Notation::Subtractive => FROM_INT.1,
+            Notation::Additive => ADDITIVE_TABLE,
+        }
+    }
+}
+
+/// Two Roman numerals are equal when they have the same value, whatever notation they are rendered
+/// in, so `IIII` equals `IV`. `Hash` only looks at the value as well, keeping it consistent with
+/// `Eq`.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+/// use std::collections::HashSet;
+///
+/// let four = Roman::from(4u32).unwrap();
+/// assert_eq!(four, four.expand());
+///
+/// let set: HashSet<Roman> = vec![four, four.expand()].into_iter().collect();
+/// assert_eq!(1, set.len());
+/// ```
+impl PartialEq for Roman {
+    fn eq(&self, other: &Roman) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Roman {}
+
+/// Compares a Roman numeral with an integer value
+///
+/// The reverse `u32 == Roman` comparison is left out on purpose: with it in scope, comparing a
+/// `u32` against a generic result such as `Digit::value_of(&digits)` or `"14".parse().unwrap()`
+/// can no longer infer the type. Dereference the numeral instead, as in `14 == *r`.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let r: Roman = "XIV".parse().unwrap();
+/// assert_eq!(r, 14u32);
+/// assert_ne!(r, 15u32);
+/// assert_eq!(14, *r);
+/// ```
+impl PartialEq<u32> for Roman {
+    fn eq(&self, other: &u32) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Compares a Roman numeral with the canonical uppercase rendering of its value, so 4 equals
+/// `"IV"` but not `"IIII"` or `"iv"`, whatever notation the numeral is rendered in.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let four = Roman::from(4u32).unwrap();
+/// assert_eq!(four, "IV");
+/// assert_ne!(four, "IIII");
+/// assert_eq!(four.expand(), "IV");
+/// ```
+impl<'a> PartialEq<&'a str> for Roman {
+    fn eq(&self, other: &&'a str) -> bool {
+        Digit::decompose(self.0, FROM_INT.1)
+            .iter()
+            .map(|d| d.to_uppercase())
+            .eq(other.chars())
+    }
+}
+
+impl Hash for Roman {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl PartialOrd for Roman {
+    fn partial_cmp(&self, other: &Roman) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Roman {
+    fn cmp(&self, other: &Roman) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+/// The default Roman numeral is `I`
+///
+/// Zero has no roman numeral, so the smallest valid numeral, which is also the multiplicative
+/// identity, is used rather than panicking or relying on the medieval `N` for *nulla*.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let one = Roman::default();
+/// assert_eq!(1, *one);
+/// assert_eq!("I", one.to_string());
+/// ```
+impl Default for Roman {
+    fn default() -> Self {
+        Roman(1, Notation::Subtractive)
+    }
+}
+
+/// Adds two Roman numerals
+///
+/// # Panics
+/// Panics if the sum is larger than `Roman::MAX`, as there is no numeral for it. Use
+/// `Roman::checked_add` to handle that case.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let sum = Roman::from(14u32).unwrap() + Roman::from(9u32).unwrap();
+/// assert_eq!("XXIII", sum.to_string());
+/// ```
+impl ops::Add for Roman {
+    type Output = Roman;
+
+    fn add(self, rhs: Roman) -> Roman {
+        match self.checked_add(rhs) {
+            Some(roman) => roman,
+            None => panic!("attempt to add with overflow"),
+        }
+    }
+}
+
+/// Subtracts two Roman numerals
+///
+/// # Panics
+/// Panics if the result is zero or negative, as there is no numeral for it. Use
+/// `Roman::checked_sub` to handle that case.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let diff = Roman::from(14u32).unwrap() - Roman::from(9u32).unwrap();
+/// assert_eq!("V", diff.to_string());
+/// ```
+impl ops::Sub for Roman {
+    type Output = Roman;
+
+    fn sub(self, rhs: Roman) -> Roman {
+        match self.checked_sub(rhs) {
+            Some(roman) => roman,
+            None => panic!("attempt to subtract with overflow"),
+        }
     }
 }
 
@@ -155,51 +934,268 @@ impl str::FromStr for Roman {
     ///
     /// let septendecim: Roman = "Xⅶ".parse().unwrap();
     /// assert_eq!(17, *septendecim);
+    ///
+    /// assert!("".parse::<Roman>().is_err(), "empty input is invalid");
+    ///
+    /// match "XIV?".parse::<Roman>() {
+    ///     Err(Error::InvalidDigitAt { ch, offset }) => assert_eq!(('?', 3), (ch, offset)),
+    ///     _ => panic!(),
+    /// }
     /// ```
     ///
-    /// Returns `Roman` , or an `septem::Error`
-    fn from_str(s: &str) -> std::result::Result<Self, Error> {
-        use std::cmp::Ordering::{Equal, Greater, Less};
+    /// Returns `Roman` , or an `septem::Error`, `Error::EmptyInput` if the string is empty and
+    /// `Error::InvalidDigitAt` with the byte offset of the first invalid char
+    fn from_str(s: &str) -> core::result::Result<Self, Error> {
+        let mut acc = Accumulator::default();
+        for (offset, ch) in s.char_indices() {
+            let digits = Digit::from_char(ch).map_err(|_| Error::InvalidDigitAt { ch, offset })?;
+            for digit in digits {
+                acc.push(digit)?;
+            }
+        }
+        acc.finish()
+    }
+}
 
-        let mut val = 0u32;
-        let mut prev: Option<u32> = None;
+/// Parses a Roman numeral from a string, ignoring surrounding ASCII whitespace
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(42, *Roman::try_from(" XLII\n").unwrap());
+///
+/// match Roman::try_from("   ") {
+///     Err(Error::EmptyInput) => {}
+///     _ => panic!(),
+/// }
+/// ```
+///
+/// Returns `Roman` , or an `septem::Error`, `Error::EmptyInput` if the string is blank and
+/// `Error::InvalidDigitAt` with an offset into the untrimmed string
+impl<'a> TryFrom<&'a str> for Roman {
+    type Error = Error;
 
-        for digits_result in s.chars().map(Digit::from_char) {
-            for digit in digits_result? {
-                let current = *digit;
+    fn try_from(s: &'a str) -> Result<Self> {
+        let trimmed = s.trim_start_matches(|c: char| c.is_ascii_whitespace());
+        let leading = s.len() - trimmed.len();
+        trimmed
+            .trim_end_matches(|c: char| c.is_ascii_whitespace())
+            .parse::<Roman>()
+            .map_err(|err| err.shift_offset(leading))
+    }
+}
 
-                if prev.is_none() {
-                    prev = Some(current);
-                    continue;
-                }
+/// Parses a Roman numeral from an owned string, in the same way as `TryFrom<&str>`
+impl TryFrom<String> for Roman {
+    type Error = Error;
 
-                let p = prev.unwrap();
-                match current.cmp(&p) {
-                    Equal => {
-                        val += p;
-                    }
-                    Less => {
-                        val += p;
-                        prev = Some(current);
-                    }
-                    Greater => {
-                        val += current - p;
-                        prev = None;
-                    }
-                }
+    fn try_from(s: String) -> Result<Self> {
+        Roman::try_from(s.as_str())
+    }
+}
+
+/// Creates a Roman numeral from a number in `Roman::MIN..=Roman::MAX`
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+/// use std::convert::TryFrom;
+///
+/// let r = Roman::try_from(42u32).unwrap();
+/// assert_eq!(42u32, r.value::<u32>());
+///
+/// match Roman::try_from(Roman::MAX + 1) {
+///     Err(Error::Overflow) => {}
+///     _ => panic!(),
+/// }
+/// ```
+///
+/// Returns `Roman` , or an `septem::Error`, `Error::InvalidNumber` for zero and `Error::Overflow`
+/// above `Roman::MAX`
+impl TryFrom<u32> for Roman {
+    type Error = Error;
+
+    fn try_from(n: u32) -> Result<Self> {
+        if n == 0 {
+            return Err(Error::InvalidNumber(n));
+        }
+        if n > Roman::MAX {
+            return Err(Error::Overflow);
+        }
+        Ok(Roman(n, Notation::Subtractive))
+    }
+}
+
+/// Creates a Roman numeral from a signed number in `Roman::MIN..=Roman::MAX`, such as a database
+/// column
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(1994, *Roman::try_from(1994i64).unwrap());
+///
+/// match Roman::try_from(-7i64) {
+///     Err(Error::InvalidNumber(n)) => assert_eq!(0, n),
+///     _ => panic!(),
+/// }
+/// ```
+///
+/// Returns `Roman` , or an `septem::Error`, `Error::InvalidNumber(0)` for zero and negative
+/// numbers and `Error::Overflow` above `Roman::MAX`
+impl TryFrom<i64> for Roman {
+    type Error = Error;
+
+    fn try_from(n: i64) -> Result<Self> {
+        if n <= 0 {
+            return Err(Error::InvalidNumber(0));
+        }
+        u32::try_from(n)
+            .map_err(|_| Error::Overflow)
+            .and_then(Roman::try_from)
+    }
+}
+
+/// Value of an ASCII digit in a const context
+const fn literal_value(b: u8) -> u32 {
+    match b {
+        b'I' | b'i' => 1,
+        b'V' | b'v' => 5,
+        b'X' | b'x' => 10,
+        b'L' | b'l' => 50,
+        b'C' | b'c' => 100,
+        b'D' | b'd' => 500,
+        b'M' | b'm' => 1000,
+        _ => panic!("invalid digit in roman numeral"),
+    }
+}
+
+/// Incremental evaluation of a digit sequence, one digit at a time
+#[derive(Default)]
+struct Accumulator {
+    val: u32,
+    prev: Option<u32>,
+}
+
+impl Accumulator {
+    /// Adds a digit, failing with `Error::Overflow` as soon as the total passes `Roman::MAX`
+    fn push(&mut self, digit: Digit) -> Result<()> {
+        use core::cmp::Ordering::{Equal, Greater, Less};
+
+        let current = *digit;
+        let p = match self.prev {
+            Some(p) => p,
+            None => {
+                self.prev = Some(current);
+                return Ok(());
+            }
+        };
+
+        match current.cmp(&p) {
+            // Saturating keeps a huge input above `Roman::MAX` instead of wrapping back into range
+            Equal => {
+                self.val = self.val.saturating_add(p);
+            }
+            Less => {
+                self.val = self.val.saturating_add(p);
+                self.prev = Some(current);
+            }
+            Greater => {
+                self.val = self.val.saturating_add(current - p);
+                self.prev = None;
             }
         }
+        if self.val > Roman::MAX {
+            return Err(Error::Overflow);
+        }
+        Ok(())
+    }
 
-        if let Some(prev) = prev {
-            val += prev;
+    fn finish(self) -> Result<Roman> {
+        if self.val == 0 && self.prev.is_none() {
+            return Err(Error::EmptyInput);
         }
+        let val = self.val.saturating_add(self.prev.unwrap_or(0));
+        if val == 0 {
+            return Err(Error::OutOfRange(val));
+        }
+        if val > Roman::MAX {
+            return Err(Error::Overflow);
+        }
+        Ok(Roman(val, Notation::Subtractive))
+    }
+}
 
-        Ok(Roman(val))
+/// Collects digits into a Roman numeral without validating them, in the same lenient way as
+/// `Digit::value_of`. Use `Roman::try_from_digit_iter` to reject non-canonical sequences.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let four: Roman = vec![Digit::I, Digit::I, Digit::I, Digit::I].into_iter().collect();
+/// assert_eq!(4, *four);
+/// ```
+impl FromIterator<Digit> for Roman {
+    fn from_iter<I: IntoIterator<Item = Digit>>(iter: I) -> Self {
+        let digits: Vec<Digit> = iter.into_iter().collect();
+        Roman(Digit::value_of(&digits), Notation::Subtractive)
     }
 }
 
+/// Renders the Roman numeral in uppercase, or in lowercase with the alternate flag. Width, fill
+/// and alignment are honored like for other `Display` types, e.g. to align a column of numerals.
+/// The precision is ignored, as a numeral cut short would read as another number.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let r = Roman::from(14u32).unwrap();
+/// assert_eq!("XIV", format!("{}", r));
+/// assert_eq!("xiv", format!("{:#}", r));
+/// assert_eq!("     XIV", format!("{:>8}", r));
+/// assert_eq!("xiv*****", format!("{:*<#8}", r));
+/// assert_eq!("XIV", format!("{:.1}", r));
+/// ```
 impl Display for Roman {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        f.write_str(&self.to_uppercase())
+        if f.alternate() {
+            pad_numeral(f, &self.to_lowercase())
+        } else {
+            pad_numeral(f, &self.to_uppercase())
+        }
+    }
+}
+
+/// Writes a rendered numeral honoring the width, fill and alignment of the formatter like
+/// `Formatter::pad`, but without ever truncating it to the precision
+pub(crate) fn pad_numeral(f: &mut Formatter, s: &str) -> FmtResult {
+    let len = s.chars().count();
+    let padding = match f.width() {
+        Some(width) if width > len => width - len,
+        _ => return f.write_str(s),
+    };
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
     }
+    Ok(())
 }
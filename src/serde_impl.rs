@@ -0,0 +1,70 @@
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Roman;
+
+/// Serializes a Roman numeral as its canonical uppercase string, e.g. `"XIV"`, whatever notation
+/// it is rendered in
+impl Serialize for Roman {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.minify().to_uppercase())
+    }
+}
+
+/// Deserializes a Roman numeral from a string by parsing it, any parse error is reported
+/// through the deserializer
+impl<'de> Deserialize<'de> for Roman {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RomanVisitor;
+
+        impl Visitor<'_> for RomanVisitor {
+            type Value = Roman;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a roman numeral string")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Roman, E> {
+                s.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(RomanVisitor)
+    }
+}
+
+/// Serializes a Roman numeral as its integer value instead of its string form
+///
+/// Use it on a field with `#[serde(with = "septem::as_value")]`.
+///
+/// # Examples
+/// ```rust
+/// # use septem::*;
+/// # use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Chapter {
+///     #[serde(with = "septem::as_value")]
+///     number: Roman,
+/// }
+///
+/// let chapter = Chapter { number: Roman::from(14u32).unwrap() };
+/// assert_eq!(r#"{"number":14}"#, serde_json::to_string(&chapter).unwrap());
+/// ```
+pub mod as_value {
+    use serde::de::{self, Deserialize, Deserializer};
+    use serde::ser::Serializer;
+
+    use crate::Roman;
+
+    pub fn serialize<S: Serializer>(roman: &Roman, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(**roman)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Roman, D::Error> {
+        let val = u32::deserialize(deserializer)?;
+        Roman::from(val).map_err(de::Error::custom)
+    }
+}
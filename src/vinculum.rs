@@ -0,0 +1,161 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::{ops, str};
+
+use crate::digit::TABLE;
+use crate::roman::pad_numeral;
+use crate::{Digit, Error, Result};
+
+/// Combining overline (U+0305) marking a digit as multiplied by 1000
+pub const OVERLINE: char = '\u{305}';
+
+/// A Roman number written with vinculum notation, where an overlined digit is multiplied by 1000
+///
+/// Values below 4000 are written as standard numerals, larger values write the thousands with
+/// overlined digits, so 5000 is `V̅` and 1,000,000 is `M̅`. The largest supported value is
+/// `Vinculum::MAX`, 3,999,999, written `M̅M̅M̅C̅M̅X̅C̅I̅X̅CMXCIX`.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let v = Vinculum::from(5000u32).unwrap();
+/// assert_eq!("V\u{305}", v.to_string());
+///
+/// let v: Vinculum = "X\u{305}MMM".parse().unwrap();
+/// assert_eq!(13000, *v);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Vinculum(u32);
+
+impl Vinculum {
+    /// The largest value that can be written with a single level of vinculum
+    pub const MAX: u32 = 3_999_999;
+
+    /// Creates a vinculum numeral for any value that implements `TryInto<u32>`, matching
+    /// `Digit::from_int_vinculum`. Requires value to be greater than 0, and at most
+    /// `Vinculum::MAX`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use septem::prelude::*;
+    /// # use septem::*;
+    ///
+    /// assert_eq!(1_000_000, *Vinculum::from(1_000_000u64).unwrap());
+    /// assert!(Vinculum::from(-1i32).is_err());
+    /// ```
+    ///
+    /// Returns `Vinculum` , or an `septem::Error::OutOfRange` for zero or values above
+    /// `Vinculum::MAX`, or an `septem::Error::Overflow` if the value does not fit in a `u32`.
+    pub fn from<T: TryInto<u32>>(val: T) -> Result<Self> {
+        let val: u32 = val.try_into().map_err(|_| Error::Overflow)?;
+        if val == 0 || val > Vinculum::MAX {
+            return Err(Error::OutOfRange(val));
+        }
+        Ok(Vinculum(val))
+    }
+
+    /// Returns the digits of the numeral, each paired with whether it is overlined
+    pub fn to_digits(self) -> Vec<(Digit, bool)> {
+        if self.0 < 4000 {
+            return Digit::decompose(self.0, TABLE)
+                .into_iter()
+                .map(|d| (d, false))
+                .collect();
+        }
+
+        let mut digits: Vec<(Digit, bool)> = Digit::decompose(self.0 / 1000, TABLE)
+            .into_iter()
+            .map(|d| (d, true))
+            .collect();
+        digits.extend(
+            Digit::decompose(self.0 % 1000, TABLE)
+                .into_iter()
+                .map(|d| (d, false)),
+        );
+        digits
+    }
+}
+
+impl ops::Deref for Vinculum {
+    type Target = u32;
+
+    /// Returns the integer representation of the vinculum numeral
+    fn deref(&self) -> &u32 {
+        &self.0
+    }
+}
+
+impl str::FromStr for Vinculum {
+    type Err = Error;
+
+    /// Creates a vinculum numeral from a string, where a digit followed by `OVERLINE` is
+    /// multiplied by 1000
+    ///
+    /// Returns `Vinculum` , or an `septem::Error`
+    fn from_str(s: &str) -> core::result::Result<Self, Error> {
+        let mut digits: Vec<(Digit, bool)> = vec![];
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            let overlined = chars.peek() == Some(&OVERLINE);
+            if overlined {
+                chars.next();
+            }
+            digits.extend(Digit::from_char(c)?.into_iter().map(|d| (d, overlined)));
+        }
+
+        if digits.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        Vinculum::from(value_of(&digits))
+    }
+}
+
+/// Renders the number with overlined digits for the thousands. Width, fill and alignment are
+/// honored like for `Roman`, counting each overline as a character of its own.
+///
+/// # Examples
+/// ```rust
+/// # use septem::prelude::*;
+/// # use septem::*;
+///
+/// let v = Vinculum::from(5000u32).unwrap();
+/// assert_eq!("  V\u{305}", format!("{:>4}", v));
+/// ```
+impl Display for Vinculum {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let mut s = String::new();
+        for (digit, overlined) in self.to_digits() {
+            s.push(digit.to_uppercase());
+            if overlined {
+                s.push(OVERLINE);
+            }
+        }
+        pad_numeral(f, &s)
+    }
+}
+
+/// Evaluates overlined digits the same way as `Digit::value_of`
+fn value_of(digits: &[(Digit, bool)]) -> u32 {
+    let values: Vec<u32> = digits
+        .iter()
+        .map(|&(d, overlined)| d.value::<u32>() * if overlined { 1000 } else { 1 })
+        .collect();
+
+    let mut total = 0u32;
+    let mut i = 0;
+    while i < values.len() {
+        if i + 1 < values.len() && values[i] < values[i + 1] {
+            total = total.saturating_add(values[i + 1] - values[i]);
+            i += 2;
+        } else {
+            total = total.saturating_add(values[i]);
+            i += 1;
+        }
+    }
+    total
+}
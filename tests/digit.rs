@@ -11,10 +11,129 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "nulla"))]
     fn from_int_invalid() {
         match Digit::from_int(0u32) {
             Err(Error::InvalidNumber(num)) => assert_eq!(0, num),
-            _ => assert!(false),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn from_int_wide() {
+        assert_eq!(vec![Digit::L], Digit::from_int(50u64).unwrap());
+        assert_eq!(vec![Digit::C], Digit::from_int(100u128).unwrap());
+        match Digit::from_int(u64::from(u32::MAX) + 1) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn from_int_additive_round_trip() {
+        let mut longest = 0;
+        for n in 1..=Digit::MAX {
+            let digits = Digit::from_int_additive(n).unwrap();
+            assert_eq!(n, Digit::value_of::<u32>(&digits));
+            assert!(digits.windows(2).all(|pair| pair[0] >= pair[1]));
+            longest = longest.max(digits.len());
+        }
+        assert_eq!(18, longest);
+        match Digit::from_int_additive(Digit::MAX + 1) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn from_int_saturating() {
+        let max = Digit::from_int(septem::Roman::MAX).unwrap();
+        let one = Digit::from_int(1u32).unwrap();
+        assert_eq!(max, Digit::from_int_saturating(septem::Roman::MAX + 1));
+        assert_eq!(max, Digit::from_int_saturating(u64::MAX));
+        assert_eq!(one, Digit::from_int_saturating(i64::MIN));
+        assert_eq!(
+            Digit::from_int(42u32).unwrap(),
+            Digit::from_int_saturating(42u8)
+        );
+        #[cfg(not(feature = "nulla"))]
+        assert_eq!(one, Digit::from_int_saturating(0u32));
+    }
+
+    #[test]
+    fn from_int_clock_style() {
+        use self::septem::Digit::*;
+        use self::septem::Style;
+
+        assert_eq!(
+            vec![I, I, I, I],
+            Digit::from_int_with(4u32, Style::Clock).unwrap()
+        );
+        assert_eq!(
+            vec![X, I, I, I, I],
+            Digit::from_int_with(14u32, Style::Clock).unwrap()
+        );
+        assert_eq!(
+            vec![X, L],
+            Digit::from_int_with(40u32, Style::Clock).unwrap()
+        );
+        assert_eq!(
+            vec![I, X],
+            Digit::from_int_with(9u32, Style::Clock).unwrap()
+        );
+        for n in 1..=Digit::MAX {
+            assert_eq!(
+                Digit::from_int(n).unwrap(),
+                Digit::from_int_with(n, Style::Standard).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_single_digit() {
+        assert_eq!(Digit::X, "X".parse::<Digit>().unwrap());
+        assert_eq!(Digit::M, "ⅿ".parse::<Digit>().unwrap());
+        for s in &["IV", "Ⅳ", "XX"] {
+            match s.parse::<Digit>() {
+                Err(Error::NotSingleDigit) => {}
+                _ => panic!("{} should be rejected", s),
+            }
+        }
+        match "A".parse::<Digit>() {
+            Err(Error::InvalidDigit(c)) => assert_eq!('A', c),
+            _ => panic!(),
+        }
+        match "".parse::<Digit>() {
+            Err(Error::EmptyInput) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn from_unicode_codepoint() {
+        for cp in 0x2160..=0x216F {
+            let upper = Digit::from_unicode_codepoint(cp).unwrap();
+            let lower = Digit::from_unicode_codepoint(cp + 0x10).unwrap();
+            assert_eq!(upper, lower);
+        }
+        assert_eq!(
+            [Digit::L],
+            Digit::from_unicode_codepoint(0x2186).unwrap()[..]
+        );
+        assert_eq!([Digit::V, Digit::I], Digit::from_char('ↅ').unwrap()[..]);
+        for &cp in &[0x215F, 0x2183, 0x2184, 0x2189, 0xD800, 0x49] {
+            match Digit::from_unicode_codepoint(cp) {
+                Err(Error::InvalidDigit(_)) => {}
+                _ => panic!("{:#x} should be rejected", cp),
+            }
+        }
+    }
+
+    #[test]
+    fn from_bytes_invalid() {
+        match Digit::from_bytes(b"MCM XC") {
+            Err(Error::InvalidDigit(digit)) => assert_eq!(' ', digit),
+            _ => panic!(),
         }
     }
 
@@ -24,8 +143,638 @@ mod tests {
         assert_eq!(5u32, i);
     }
 
+    #[test]
+    fn digit_ordering() {
+        assert!(Digit::X > Digit::V);
+        assert!(Digit::I < Digit::D);
+        assert_eq!(Digit::C, Digit::C.max(Digit::L));
+    }
+
     #[test]
     fn digit_display() {
         assert_eq!('V', (&Digit::V).into());
     }
+
+    #[test]
+    fn digit_display_ignores_precision() {
+        assert_eq!("X", format!("{:.0}", Digit::X));
+        assert_eq!("-X-", format!("{:-^3.0}", Digit::X));
+    }
+
+    #[test]
+    fn decomposition_table_matches_from_int() {
+        for &(value, digits) in Digit::decomposition_table() {
+            assert_eq!(digits, &Digit::from_int(value).unwrap()[..]);
+        }
+        assert_eq!(septem::VALUE_TABLE, Digit::decomposition_table());
+        #[cfg(feature = "archaic")]
+        {
+            let table = Digit::decomposition_table();
+            assert!(table.iter().any(|&(value, _)| value > Digit::MAX));
+            assert_eq!((100_000, &[Digit::HundredThousand][..]), table[0]);
+            assert_eq!(
+                &Digit::from_int(4000u32).unwrap()[..],
+                table.iter().find(|&&(value, _)| value == 4000).unwrap().1
+            );
+        }
+    }
+
+    #[test]
+    fn from_value_inverts_value() {
+        for &d in &[
+            Digit::I,
+            Digit::V,
+            Digit::X,
+            Digit::L,
+            Digit::C,
+            Digit::D,
+            Digit::M,
+        ] {
+            assert_eq!(Some(d), Digit::from_value(d.value()));
+        }
+        assert_eq!(None, Digit::from_value(0));
+        assert_eq!(None, Digit::from_value(4));
+    }
+
+    #[test]
+    fn slice_round_trip() {
+        for n in 1..=3999u32 {
+            let digits = Digit::from_int(n).unwrap();
+            let s = Digit::to_string_slice(&digits);
+            assert_eq!(n, *s.parse::<septem::Roman>().unwrap());
+        }
+    }
+
+    #[test]
+    fn value_of_round_trip() {
+        for n in 1..=Digit::MAX {
+            let digits = Digit::from_int(n).unwrap();
+            assert_eq!(n, Digit::value_of::<u32>(&digits));
+            assert_eq!(
+                n,
+                Digit::value_of::<u32>(&Digit::iter_from_int(n).unwrap().collect::<Vec<_>>())
+            );
+        }
+        #[cfg(not(feature = "archaic"))]
+        match Digit::from_int(Digit::MAX + 1) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+        #[cfg(not(feature = "archaic"))]
+        match Digit::iter_from_int(Digit::MAX + 1) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn checked_value_of() {
+        let digits = vec![Digit::M; 66];
+        assert_eq!(66000u32, Digit::checked_value_of(&digits).unwrap());
+        match Digit::checked_value_of::<u16>(&digits) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+        assert_eq!(
+            14u8,
+            Digit::checked_value_of(&[Digit::X, Digit::I, Digit::V]).unwrap()
+        );
+        match Digit::checked_value_of::<u8>(&[Digit::M]) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+        match Digit::checked_value_of::<i8>(&[Digit::C, Digit::C]) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn value_of_chars_matches_value_of() {
+        for n in 1..=3999u32 {
+            let s = Digit::to_string_slice(&Digit::from_int(n).unwrap());
+            assert_eq!(n, Digit::value_of_chars(s.chars()).unwrap());
+        }
+        for s in &["IIII", "IXI", "VX", "Ⅻ", "ⅯⅭⅯⅩⅭⅣ"] {
+            let digits: Vec<Digit> = s
+                .chars()
+                .flat_map(|c| Digit::from_char(c).unwrap())
+                .collect();
+            assert_eq!(
+                Digit::value_of::<u32>(&digits),
+                Digit::value_of_chars(s.chars()).unwrap()
+            );
+        }
+        match Digit::value_of_chars("".chars()) {
+            Err(Error::EmptyInput) => {}
+            _ => panic!(),
+        }
+        match Digit::value_of_chars("XAX".chars()) {
+            Err(Error::InvalidDigit(c)) => assert_eq!('A', c),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn value_of_checked() {
+        use self::septem::Digit::*;
+        for n in 1..=3999u32 {
+            assert_eq!(
+                n,
+                Digit::value_of_checked(&Digit::from_int(n).unwrap()).unwrap()
+            );
+        }
+        match Digit::value_of_checked::<u32>(&[I, X, I]) {
+            Err(Error::NonCanonical(position)) => assert_eq!(2, position),
+            _ => panic!(),
+        }
+        match Digit::value_of_checked::<u32>(&[]) {
+            Err(Error::EmptyInput) => {}
+            _ => panic!(),
+        }
+        match Digit::value_of_checked::<u8>(&[M]) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn cmp_sequences_beyond_u32() {
+        use std::cmp::Ordering;
+
+        let big = vec![Digit::M; 5_000_000];
+        let mut bigger = big.clone();
+        bigger.push(Digit::I);
+        assert_eq!(Ordering::Less, Digit::cmp_sequences(&big, &bigger));
+        assert_eq!(Ordering::Greater, Digit::cmp_sequences(&bigger, &big));
+        assert_eq!(Ordering::Equal, Digit::cmp_sequences(&big, &big));
+        assert_eq!(
+            Ordering::Equal,
+            Digit::cmp_sequences(
+                &[Digit::V, Digit::I, Digit::I, Digit::I, Digit::I],
+                &[Digit::I, Digit::X]
+            )
+        );
+    }
+
+    #[test]
+    fn validate_canonical() {
+        for n in 1..=3999u32 {
+            assert!(Digit::validate(&Digit::from_int(n).unwrap()).is_ok());
+        }
+    }
+
+    #[test]
+    fn validate_rejects_non_canonical() {
+        use self::septem::Digit::*;
+        let invalid: &[&[Digit]] = &[
+            &[I, I, I, I],
+            &[V, V],
+            &[L, L],
+            &[I, C],
+            &[X, M],
+            &[I, X, I],
+            &[V, I, V],
+            &[I, I, V],
+            &[M, M, M, M],
+        ];
+        for digits in invalid {
+            match Digit::validate(digits) {
+                Err(Error::NonCanonical(_)) => {}
+                _ => panic!("{:?} should not be canonical", digits),
+            }
+        }
+    }
+
+    #[test]
+    fn iter_from_int_matches_from_int() {
+        for n in 1..=3999u32 {
+            let mut digits = Digit::iter_from_int(n).unwrap();
+            let expected = Digit::from_int(n).unwrap();
+            assert_eq!(expected.len(), digits.len());
+            digits.next();
+            assert_eq!(expected.len() - 1, digits.len());
+            assert_eq!(&expected[1..], &digits.collect::<Vec<_>>()[..]);
+        }
+    }
+
+    #[test]
+    fn is_representable() {
+        assert!(!Digit::is_representable(0));
+        assert!(Digit::is_representable(1994));
+        #[cfg(not(feature = "archaic"))]
+        assert!(!Digit::is_representable(4000));
+    }
+
+    #[test]
+    fn canonicalize() {
+        use self::septem::Digit::*;
+        assert_eq!(vec![I, V], Digit::canonicalize(&[I, I, I, I]).unwrap());
+        assert_eq!(vec![I, X], Digit::canonicalize(&[V, I, I, I, I]).unwrap());
+        assert_eq!(vec![M, C, M], Digit::canonicalize(&[M, C, M]).unwrap());
+    }
+
+    #[test]
+    #[cfg(not(feature = "nulla"))]
+    fn canonicalize_empty() {
+        match Digit::canonicalize(&[]) {
+            Err(Error::InvalidNumber(num)) => assert_eq!(0, num),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn parse_lenient_ignores_format_characters() {
+        for s in &[
+            "\u{200E}MCMXCIV",
+            "MCM\u{200B}XCIV",
+            "\u{FEFF}MCMXCIV\u{200F}",
+            "MCM\u{2066}XC\u{2069}IV",
+        ] {
+            assert_eq!((1994, true), Digit::parse_lenient(s).unwrap());
+            assert!(s.parse::<septem::Roman>().is_err());
+        }
+    }
+
+    #[test]
+    fn parse_prefix() {
+        let (roman, rest) = Digit::parse_prefix("MCMXCIV").unwrap();
+        assert_eq!(1994, *roman);
+        assert_eq!("", rest);
+
+        let (roman, rest) = Digit::parse_prefix("ⅫAB XI").unwrap();
+        assert_eq!(12, *roman);
+        assert_eq!("AB XI", rest);
+
+        match Digit::parse_prefix("") {
+            Err(Error::EmptyInput) => {}
+            _ => panic!(),
+        }
+        match Digit::parse_prefix(" XI") {
+            Err(Error::InvalidDigit(c)) => assert_eq!(' ', c),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn sum_is_additive() {
+        let digits = Digit::from_int(1994u32).unwrap();
+        assert_eq!(2216u32, digits.iter().sum());
+        assert_eq!(2216u32, digits.into_iter().sum());
+    }
+
+    #[test]
+    fn parse_lenient() {
+        assert_eq!((9, false), Digit::parse_lenient("VIIII").unwrap());
+        assert_eq!((9, true), Digit::parse_lenient(" i x ").unwrap());
+        assert_eq!((12, true), Digit::parse_lenient("Ⅻ").unwrap());
+        match Digit::parse_lenient("  ") {
+            Err(Error::EmptyInput) => {}
+            _ => panic!(),
+        }
+        match Digit::parse_lenient("XIZ") {
+            Err(Error::InvalidDigit(c)) => assert_eq!('Z', c),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn write_ascii() {
+        let mut buf = [0u8; Digit::MAX_ASCII_LEN];
+        let mut longest = 0;
+        for n in 1..=Digit::MAX {
+            let len = Digit::write_ascii(n, &mut buf).unwrap();
+            let expected = Digit::to_string_slice(&Digit::from_int(n).unwrap());
+            assert_eq!(expected.as_bytes(), &buf[..len]);
+            longest = longest.max(len);
+        }
+        assert_eq!(Digit::MAX_ASCII_LEN, longest);
+        match Digit::write_ascii(8, &mut buf[..3]) {
+            Err(Error::BufferTooSmall(needed)) => assert_eq!(4, needed),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn from_units_matches_decomposition() {
+        for n in 1..10u32 {
+            let walked: Vec<Digit> = Digit::iter_from_int(n).unwrap().collect();
+            assert_eq!(walked[..], *Digit::from_units(n).unwrap());
+            assert_eq!(walked[..], Digit::from_int(n).unwrap()[..]);
+        }
+        match Digit::from_units(10) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn count_for_matches_from_int() {
+        for n in 1..=Digit::MAX {
+            assert_eq!(
+                Digit::from_int(n).unwrap().len(),
+                Digit::count_for(n).unwrap()
+            );
+        }
+        #[cfg(not(feature = "archaic"))]
+        match Digit::count_for(Digit::MAX + 1) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn subtractive_pairs_match_value_table() {
+        for &(first, second, value) in Digit::subtractive_pairs() {
+            assert!(Digit::is_subtractive_pair(first, second));
+            assert_eq!(value, Digit::value_of::<u32>(&[first, second]));
+            assert!(septem::VALUE_TABLE.contains(&(value, &[first, second][..])));
+        }
+        let pairs = septem::VALUE_TABLE
+            .iter()
+            .filter(|&&(value, d)| d.len() == 2 && value <= Digit::MAX);
+        assert_eq!(Digit::subtractive_pairs().len(), pairs.count());
+    }
+
+    #[test]
+    fn digit_frequencies() {
+        use std::collections::HashMap;
+
+        let mut counts = HashMap::new();
+        for digit in Digit::from_int(3888u32).unwrap() {
+            *counts.entry(digit).or_insert(0) += 1;
+        }
+        assert_eq!(Some(&3), counts.get(&Digit::M));
+        assert_eq!(Some(&1), counts.get(&Digit::D));
+        assert_eq!(Some(&3), counts.get(&Digit::X));
+        assert_eq!(7, counts.len());
+    }
+
+    #[test]
+    fn to_string_fast_matches_from_int() {
+        for n in 1..=Digit::MAX {
+            let expected = Digit::to_string_slice(&Digit::from_int(n).unwrap());
+            assert_eq!(expected, Digit::to_string_fast(n).unwrap());
+        }
+        match Digit::to_string_fast(Digit::MAX + 1) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn is_valid() {
+        for n in 1..=Digit::MAX {
+            let s = Digit::to_string_slice(&Digit::from_int(n).unwrap());
+            assert!(septem::is_valid(&s), "{} should be valid", s);
+        }
+        for s in &["", " ", "IIII", "VX", "MMMM", "X I", "IC"] {
+            assert!(!septem::is_valid(s), "{} should be invalid", s);
+        }
+    }
+
+    #[test]
+    fn all_round_trips_through_char() {
+        for digit in Digit::all() {
+            assert_eq!(
+                vec![*digit],
+                Digit::from_char(digit.to_uppercase()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn deref_matches_value() {
+        for digit in Digit::all() {
+            assert_eq!(**digit, digit.value::<u32>());
+            assert_eq!(**digit, digit.base_value());
+            assert_eq!(**digit, digit.value_const());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "unicode"))]
+mod unicode_tests {
+    extern crate septem;
+    use self::septem::{Digit, Roman};
+
+    #[test]
+    fn from_char_fullwidth() {
+        assert_eq!(vec![Digit::I], Digit::from_char('Ｉ').unwrap());
+        assert_eq!(vec![Digit::D], Digit::from_char('ｄ').unwrap());
+        assert_eq!(1994, *"ＭＣＭＸＣＩＶ".parse::<Roman>().unwrap());
+    }
+}
+
+#[cfg(all(test, feature = "nulla"))]
+mod nulla_tests {
+    extern crate septem;
+    use self::septem::{Digit, Error};
+
+    #[test]
+    fn nulla_round_trip() {
+        let zero = Digit::from_int(0u32).unwrap();
+        assert_eq!(vec![Digit::Nulla], zero);
+        assert_eq!(zero, Digit::from_char('n').unwrap());
+        assert_eq!(0u32, Digit::value_of(&zero));
+        assert!(Digit::validate(&zero).is_ok());
+    }
+
+    #[test]
+    fn nulla_mixed_is_rejected() {
+        let digits = vec![Digit::X, Digit::Nulla];
+        match Digit::checked_value_of::<u32>(&digits) {
+            Err(Error::NonCanonical(position)) => assert_eq!(1, position),
+            _ => panic!(),
+        }
+        assert!(Digit::validate(&digits).is_err());
+    }
+}
+
+#[cfg(all(test, feature = "archaic"))]
+mod archaic_tests {
+    extern crate septem;
+    use self::septem::{Digit, Error};
+
+    #[test]
+    fn from_int_archaic_range() {
+        for n in (1..=Digit::MAX_ARCHAIC).step_by(7) {
+            let digits = Digit::from_int(n).unwrap();
+            assert_eq!(n, Digit::value_of::<u32>(&digits));
+            assert_eq!(digits.len(), Digit::count_for(n).unwrap());
+        }
+        assert_eq!(
+            Digit::MAX_ARCHAIC,
+            Digit::value_of::<u32>(&Digit::from_int(Digit::MAX_ARCHAIC).unwrap())
+        );
+        match Digit::from_int(Digit::MAX_ARCHAIC + 1) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn from_int_archaic_digits() {
+        use self::septem::Digit::*;
+        assert_eq!(
+            [FiftyThousand, TenThousand],
+            Digit::from_int(60_000u32).unwrap()[..]
+        );
+        assert_eq!([M, FiveThousand], Digit::from_int(4000u32).unwrap()[..]);
+        assert_eq!(
+            [TenThousand, HundredThousand, M, TenThousand, I],
+            Digit::from_int(99_001u32).unwrap()[..]
+        );
+        assert_eq!(
+            Digit::from_int(3999u32).unwrap()[..],
+            [M, M, M, C, M, X, C, I, X]
+        );
+        match Digit::from_int_additive(4000u32) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn conversions_follow_archaic_range() {
+        for n in (1..=Digit::MAX_ARCHAIC).step_by(97) {
+            let digits = Digit::from_int(n).unwrap();
+            assert!(Digit::is_representable(n));
+            assert!(Digit::validate(&digits).is_ok(), "{}", n);
+            assert_eq!(digits, Digit::try_from_int(n).unwrap());
+            assert_eq!(digits, Digit::from_int_saturating(n));
+            assert_eq!(
+                digits[..],
+                Digit::iter_from_int(n).unwrap().collect::<Vec<_>>()[..]
+            );
+            assert_eq!(digits.len(), Digit::iter_from_int(n).unwrap().len());
+        }
+        assert_eq!(
+            Digit::from_int(Digit::MAX_ARCHAIC).unwrap(),
+            Digit::from_int_saturating(u32::MAX)
+        );
+        assert!(!Digit::is_representable(Digit::MAX_ARCHAIC + 1));
+        assert!(septem::is_valid("ↁ"));
+        assert!(septem::is_valid("ↈↈↈↂↈMↂCMXCIX"));
+        assert!(!septem::is_valid("ↁↁ"));
+    }
+
+    #[test]
+    fn ascii_output_stops_at_standard_max() {
+        let mut buf = [0u8; 32];
+        match Digit::write_ascii(Digit::MAX + 1, &mut buf) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+        match Digit::to_string_fast(Digit::MAX + 1) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn deref_archaic() {
+        use self::septem::Digit::*;
+        assert_eq!(1000, *OneThousandOld);
+        assert_eq!(5000, *FiveThousand);
+        assert_eq!(10000, *TenThousand);
+        assert_eq!(50000, *FiftyThousand);
+        assert_eq!(100000, *HundredThousand);
+        assert_eq!(100000u32, HundredThousand.into());
+    }
+
+    #[test]
+    fn from_apostrophus_clusters() {
+        use self::septem::Digit::*;
+        let cases: &[(&str, Digit)] = &[
+            ("IↃ", D),
+            ("CIↃ", OneThousandOld),
+            ("IↃↃ", FiveThousand),
+            ("CCIↃↃ", TenThousand),
+            ("IↃↃↃ", FiftyThousand),
+            ("CCCIↃↃↃ", HundredThousand),
+        ];
+        for &(s, digit) in cases {
+            assert_eq!(vec![digit], Digit::from_apostrophus(s).unwrap());
+        }
+        assert_eq!(
+            vec![C, OneThousandOld],
+            Digit::from_apostrophus("CCIↃ").unwrap()
+        );
+    }
+
+    #[test]
+    fn from_apostrophus_malformed() {
+        for s in &["Ↄ", "IIↃↃↃↃ", "CCIↃↃↃ"] {
+            match Digit::from_apostrophus(s) {
+                Err(Error::InvalidDigit(c)) => assert_eq!('Ↄ', c),
+                _ => panic!("{} should be rejected", s),
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "num-bigint"))]
+mod bigint_tests {
+    extern crate num_bigint;
+    extern crate septem;
+    use self::num_bigint::BigUint;
+    use self::septem::{Digit, Error};
+
+    #[test]
+    fn value_of_beyond_u32() {
+        let digits = vec![Digit::M; 4_294_968];
+        let expected = BigUint::from(4_294_968u32) * BigUint::from(1000u32);
+        assert_eq!(expected, Digit::value_of::<BigUint>(&digits));
+    }
+
+    #[test]
+    fn from_bigint() {
+        let digits = Digit::from_bigint(&BigUint::from(1994u32)).unwrap();
+        assert_eq!(Digit::from_int(1994u32).unwrap(), digits);
+        let max = BigUint::from(septem::Roman::MAX);
+        assert_eq!(
+            Digit::from_int(septem::Roman::MAX).unwrap(),
+            Digit::from_bigint(&max).unwrap()
+        );
+        match Digit::from_bigint(&(max + 1u32)) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+        match Digit::from_bigint(&BigUint::from(u64::MAX)) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "smallvec"))]
+mod smallvec_tests {
+    extern crate septem;
+    use self::septem::{Digit, Error, Roman};
+
+    #[test]
+    fn inline_matches_vec() {
+        for n in 1..=Digit::MAX {
+            let digits = Digit::from_int_inline(n).unwrap();
+            assert!(!digits.spilled());
+            assert_eq!(Digit::from_int(n).unwrap()[..], digits[..]);
+            let r = Roman::from(n).unwrap().expand();
+            assert_eq!(r.to_digits()[..], r.to_digits_inline()[..]);
+        }
+        for c in "IVXLCDMⅧⅻ".chars() {
+            assert_eq!(
+                Digit::from_char(c).unwrap()[..],
+                Digit::from_char_inline(c).unwrap()[..]
+            );
+        }
+        match Digit::from_int_inline(u64::MAX) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+        match Digit::from_char_inline('A') {
+            Err(Error::InvalidDigit(c)) => assert_eq!('A', c),
+            _ => panic!(),
+        }
+    }
 }
@@ -0,0 +1,43 @@
+#[cfg(all(test, feature = "fractions"))]
+mod tests {
+    extern crate septem;
+    use self::septem::{Error, Fraction};
+
+    #[test]
+    fn round_trip() {
+        for n in 1..12u8 {
+            let f = Fraction::from_twelfths(n).unwrap();
+            let parsed: Fraction = f.to_string().parse().unwrap();
+            assert_eq!(f, parsed);
+            assert_eq!(f64::from(n) / 12.0, parsed.value());
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(8, "S··".parse::<Fraction>().unwrap().twelfths());
+        assert_eq!(6, "s".parse::<Fraction>().unwrap().twelfths());
+        assert_eq!(1, "·".parse::<Fraction>().unwrap().twelfths());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        match "".parse::<Fraction>() {
+            Err(Error::EmptyInput) => {}
+            _ => panic!(),
+        }
+        for s in &["······", "SS", "S······", "·S", "S.."] {
+            assert!(s.parse::<Fraction>().is_err(), "{} should be rejected", s);
+        }
+    }
+
+    #[test]
+    fn from_twelfths_out_of_range() {
+        for &n in &[0u8, 12, 200] {
+            match Fraction::from_twelfths(n) {
+                Err(Error::OutOfRange(v)) => assert_eq!(u32::from(n), v),
+                _ => panic!(),
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    extern crate septem;
+    use self::septem::{
+        Digit, Error, Parser, RomanParser, DEFAULT_DECORATIONS, DEFAULT_SEPARATORS,
+    };
+
+    #[test]
+    fn strip_decorations() {
+        let parser = Parser::new().strip_decorations(DEFAULT_DECORATIONS);
+        assert_eq!(14, *parser.parse("XIV.").unwrap());
+        assert_eq!(14, *parser.parse("no. xiv").unwrap());
+        assert_eq!(14, *parser.parse("  Nr. XIV  ").unwrap());
+    }
+
+    #[test]
+    fn decorations_are_returned() {
+        let parser = Parser::new().strip_decorations(DEFAULT_DECORATIONS);
+        let (roman, leading, trailing) = parser.parse_decorated("§ LX.").unwrap();
+        assert_eq!(60, *roman);
+        assert_eq!("§ ", leading);
+        assert_eq!(".", trailing);
+    }
+
+    #[test]
+    fn no_decorations_by_default() {
+        match Parser::new().parse("XIV.") {
+            Err(Error::InvalidDigitAt { ch, offset }) => assert_eq!(('.', 3), (ch, offset)),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn strict_case() {
+        let parser = Parser::new().strict_case();
+        assert_eq!(1994, *parser.parse("MCMXCIV").unwrap());
+        assert_eq!(1994, *parser.parse("mcmxciv").unwrap());
+        assert_eq!(12, *parser.parse("ⅹⅱ").unwrap());
+        match parser.parse("MCMXCiv") {
+            Err(Error::MixedCase(position)) => assert_eq!(5, position),
+            _ => panic!(),
+        }
+        assert_eq!(1994, *Parser::new().parse("McMxCiV").unwrap());
+    }
+
+    #[test]
+    fn strict_case_with_decorations() {
+        let parser = Parser::new()
+            .strip_decorations(DEFAULT_DECORATIONS)
+            .strict_case();
+        assert_eq!(14, *parser.parse("no. XIV").unwrap());
+        match parser.parse("No. XiV") {
+            Err(Error::MixedCase(position)) => assert_eq!(5, position),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn roman_parser_offsets() {
+        let digits: Vec<(Digit, usize)> =
+            RomanParser::new("MⅫiv").collect::<Result<_, _>>().unwrap();
+        assert_eq!(
+            vec![
+                (Digit::M, 0),
+                (Digit::X, 1),
+                (Digit::I, 1),
+                (Digit::I, 1),
+                (Digit::I, 4),
+                (Digit::V, 5)
+            ],
+            digits
+        );
+    }
+
+    #[test]
+    fn roman_parser_fuses_after_error() {
+        let mut digits = RomanParser::new("XaV");
+        assert_eq!((Digit::X, 0), digits.next().unwrap().unwrap());
+        match digits.next() {
+            Some(Err(Error::InvalidDigit(c))) => assert_eq!('a', c),
+            _ => panic!(),
+        }
+        assert!(digits.next().is_none());
+        assert!(digits.next().is_none());
+        assert!(RomanParser::new("").next().is_none());
+    }
+
+    #[test]
+    fn separators() {
+        let parser = Parser::new().separators(DEFAULT_SEPARATORS);
+        assert_eq!(1994, *parser.parse("M·CM·XC·IV").unwrap());
+        assert_eq!(1994, *parser.parse("·M..CM XC·IV·").unwrap());
+        assert_eq!(1994, *parser.parse("MCMXCIV").unwrap());
+        match parser.parse("· ·") {
+            Err(Error::EmptyInput) => {}
+            _ => panic!(),
+        }
+        match Parser::new().separators(&['-']).parse("M·CM") {
+            Err(Error::InvalidDigitAt { ch, offset }) => assert_eq!(('·', 1), (ch, offset)),
+            _ => panic!(),
+        }
+        match Parser::new().separators(&['-']).parse("M-C-?") {
+            Err(Error::InvalidDigitAt { ch, offset }) => assert_eq!(('?', 4), (ch, offset)),
+            _ => panic!(),
+        }
+    }
+}
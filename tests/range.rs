@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    extern crate septem;
+    use self::septem::{Error, Roman};
+
+    #[test]
+    fn inclusive_and_exclusive() {
+        let values: Vec<u32> = septem::range(1..=5).unwrap().map(|r| *r).collect();
+        assert_eq!(vec![1, 2, 3, 4, 5], values);
+        let values: Vec<u32> = septem::range(1..5).unwrap().map(|r| *r).collect();
+        assert_eq!(vec![1, 2, 3, 4], values);
+        assert_eq!(0, septem::range(5..5).unwrap().count());
+    }
+
+    #[test]
+    fn full_range() {
+        let range = septem::range(..).unwrap();
+        assert_eq!(Roman::MAX as usize, range.len());
+        for (n, r) in (1u32..).zip(range) {
+            assert_eq!(Roman::from(n).unwrap(), r);
+        }
+    }
+
+    #[test]
+    fn reversed() {
+        let numerals: Vec<String> = septem::range(8..=10)
+            .unwrap()
+            .rev()
+            .map(|r| r.to_string())
+            .collect();
+        assert_eq!(vec!["X", "IX", "VIII"], numerals);
+    }
+
+    #[test]
+    fn out_of_range() {
+        match septem::range(0..=3) {
+            Err(Error::OutOfRange(n)) => assert_eq!(0, n),
+            _ => panic!(),
+        }
+        match septem::range(3990..=Roman::MAX + 1) {
+            Err(Error::OutOfRange(n)) => assert_eq!(Roman::MAX + 1, n),
+            _ => panic!(),
+        }
+        assert!(septem::range(3990..Roman::MAX + 1).is_ok());
+    }
+}
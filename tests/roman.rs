@@ -2,7 +2,7 @@
 mod tests {
     extern crate septem;
     use self::septem::prelude::*;
-    use self::septem::{Error, Roman};
+    use self::septem::{Case, Error, Roman, SMALL};
 
     #[test]
     fn from_valid() {
@@ -36,16 +36,90 @@ mod tests {
     #[test]
     fn from_str_invalid() {
         match Roman::from_str("DXSIX") {
-            Err(Error::InvalidDigit(digit)) => assert_eq!('S', digit),
-            _ => assert!(false),
+            Err(Error::InvalidDigitAt { ch, offset }) => assert_eq!(('S', 2), (ch, offset)),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn from_str_invalid_offset_is_in_bytes() {
+        match Roman::from_str("Ⅻ?") {
+            Err(Error::InvalidDigitAt { ch, offset }) => assert_eq!(('?', 3), (ch, offset)),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn from_str_empty() {
+        match Roman::from_str("") {
+            Err(Error::EmptyInput) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn try_from_str() {
+        use std::convert::TryFrom;
+
+        assert_eq!(42, *Roman::try_from("XLII").unwrap());
+        assert_eq!(42, *Roman::try_from(String::from("\tXLII ")).unwrap());
+        match Roman::try_from(" \n ") {
+            Err(Error::EmptyInput) => {}
+            _ => panic!(),
+        }
+        match Roman::try_from("X LII") {
+            Err(Error::InvalidDigitAt { ch, offset }) => assert_eq!((' ', 1), (ch, offset)),
+            _ => panic!(),
+        }
+        match Roman::try_from("  XL?I") {
+            Err(Error::InvalidDigitAt { ch, offset }) => assert_eq!(('?', 4), (ch, offset)),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn try_from_int() {
+        use std::convert::TryFrom;
+
+        for n in 1..=3999u32 {
+            assert_eq!(n, Roman::try_from(n).unwrap().value::<u32>());
+            assert_eq!(n, Roman::try_from(i64::from(n)).unwrap().value::<u32>());
+        }
+        for &n in &[0i64, -1, i64::MIN] {
+            match Roman::try_from(n) {
+                Err(Error::InvalidNumber(v)) => assert_eq!(0, v),
+                _ => panic!(),
+            }
+        }
+        for &n in &[i64::from(Roman::MAX) + 1, i64::from(u32::MAX) + 1, i64::MAX] {
+            match Roman::try_from(n) {
+                Err(Error::Overflow) => {}
+                _ => panic!(),
+            }
         }
     }
 
     #[test]
     fn from_int_too_high() {
-        match Roman::from(5003u32) {
-            Err(Error::OutOfRange(digit)) => assert_eq!(5003, digit),
-            _ => assert!(false),
+        match Roman::from(Roman::MAX + 4) {
+            Err(Error::OutOfRange(digit)) => assert_eq!(Roman::MAX + 4, digit),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn max_and_min() {
+        assert_eq!(Roman::MAX, *Roman::max_value());
+        assert_eq!(Roman::MIN, *Roman::min_value());
+        assert_eq!(Roman::max_value(), Roman::from(Roman::MAX).unwrap());
+        assert!(Roman::from(Roman::MIN - 1).is_err());
+        #[cfg(not(feature = "archaic"))]
+        assert_eq!("MMMCMXCIX", Roman::max_value().to_string());
+        let max = Roman::max_value().to_string();
+        assert_eq!(Roman::max_value(), max.parse::<Roman>().unwrap());
+        match format!("{}I", max).parse::<Roman>() {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
         }
     }
 
@@ -58,6 +132,18 @@ mod tests {
         assert_eq!(vec![D, X, X, X, I, I], r.to_digits());
     }
 
+    #[test]
+    fn digit_at() {
+        for n in 1..=3999u32 {
+            let r = Roman::from(n).unwrap();
+            let digits = r.to_digits();
+            for (i, &d) in digits.iter().enumerate() {
+                assert_eq!(Some(d), r.digit(i));
+            }
+            assert_eq!(None, r.digit(digits.len()));
+        }
+    }
+
     #[test]
     fn display_roman() {
         let r = Roman::from_str("DXXIX");
@@ -67,4 +153,265 @@ mod tests {
         assert_eq!("dxxix", r.to_lowercase());
         assert_eq!("DXXIX", format!("{}", r));
     }
+
+    #[test]
+    fn display_padding() {
+        let r = Roman::from(14u32).unwrap();
+        assert_eq!("XIV     ", format!("{:<8}", r));
+        assert_eq!("     XIV", format!("{:>8}", r));
+        assert_eq!("  XIV   ", format!("{:^8}", r));
+        assert_eq!("--xiv---", format!("{:-^#8}", r));
+        assert_eq!("XIV", format!("{:2}", r));
+        assert_eq!("XIV", format!("{:.1}", r));
+        assert_eq!("  XIV", format!("{:>5.1}", r));
+    }
+
+    #[test]
+    fn to_unicode() {
+        for n in 1..=12u32 {
+            let r = Roman::from(n).unwrap();
+            let upper = r.to_unicode();
+            assert_eq!(1, upper.chars().count());
+            assert_eq!(r, upper.parse::<Roman>().unwrap());
+            assert_eq!(r, r.to_unicode_lowercase().parse::<Roman>().unwrap());
+        }
+        assert_eq!("Ⅼ", Roman::from(50u32).unwrap().to_unicode());
+        assert_eq!("ⅾ", Roman::from(500u32).unwrap().to_unicode_lowercase());
+        assert_eq!("XIII", Roman::from(13u32).unwrap().to_unicode());
+        assert_eq!("IIII", Roman::from(4u32).unwrap().expand().to_unicode());
+    }
+
+    #[test]
+    fn display_lowercase() {
+        let r = Roman::from(1994u32).unwrap();
+        assert_eq!("mcmxciv", format!("{:#}", r));
+        assert_eq!("mcmxciv", r.to_string_case(Case::Lower));
+        assert_eq!("MCMXCIV", r.to_string_case(Case::Upper));
+        let four = Roman::from(4u32).unwrap().expand();
+        assert_eq!("iiii", format!("{:#}", four));
+    }
+
+    #[test]
+    fn default_is_one() {
+        let mut numerals = vec![Roman::from(5u32).unwrap()];
+        numerals.resize(3, Roman::default());
+        assert_eq!(
+            vec![5, 1, 1],
+            numerals.iter().map(|r| **r).collect::<Vec<u32>>()
+        );
+    }
+
+    #[test]
+    fn try_from_digit_iter() {
+        use self::septem::Digit::*;
+        let r = Roman::try_from_digit_iter(vec![M, C, M, X, C, I, V]);
+        assert_eq!(1994, *r.unwrap());
+        match Roman::try_from_digit_iter(vec![I, C]) {
+            Err(Error::NonCanonical(position)) => assert_eq!(1, position),
+            _ => panic!(),
+        }
+        match Roman::try_from_digit_iter(vec![]) {
+            Err(Error::EmptyInput) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn parse_classical() {
+        for n in 1..=3999u32 {
+            let r = Roman::from(n).unwrap();
+            assert_eq!(r, Roman::parse_classical(&r.to_string()).unwrap());
+            assert_eq!(r, Roman::parse_classical(&r.to_lowercase()).unwrap());
+        }
+        match Roman::parse_classical("MMMCMXCIXI") {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+        match Roman::parse_classical("IIII") {
+            Err(Error::NonCanonical(position)) => assert_eq!(3, position),
+            _ => panic!(),
+        }
+        for s in &["Ⅻ", "ＸＩＶ", "X\u{305}", "ↂ"] {
+            match Roman::parse_classical(s) {
+                Err(Error::InvalidDigit(_)) => {}
+                _ => panic!("{} should be rejected", s),
+            }
+        }
+        match Roman::parse_classical("N") {
+            Err(Error::InvalidDigit(c)) => assert_eq!('N', c),
+            _ => panic!(),
+        }
+        match Roman::parse_classical(" XIV") {
+            Err(Error::InvalidDigit(c)) => assert_eq!(' ', c),
+            _ => panic!(),
+        }
+        match Roman::parse_classical("") {
+            Err(Error::EmptyInput) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn subtractive_pairs() {
+        use self::septem::Digit::*;
+        let r = Roman::from(449u32).unwrap();
+        assert_eq!(vec![(C, D), (X, L), (I, X)], r.subtractive_pairs());
+        let r = Roman::from(3888u32).unwrap();
+        assert!(r.subtractive_pairs().is_empty());
+    }
+
+    #[test]
+    fn from_str_does_not_wrap_around() {
+        // Enough digits for the total to pass u32::MAX and wrap back to a small value
+        let s = "M".repeat(4_294_968);
+        match Roman::from_str(&s) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+        #[cfg(feature = "std")]
+        match Roman::from_reader(s.as_bytes()) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_reader_stops_at_overflow() {
+        match Roman::from_reader(std::io::repeat(b'M')) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_reader() {
+        let r = Roman::from_reader(&b"  dxxix\r\n"[..]).unwrap();
+        assert_eq!(529, *r);
+        match Roman::from_reader(&b"XIV\nXV"[..]) {
+            Err(Error::InvalidDigit(digit)) => assert_eq!('X', digit),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn small_matches_conversion() {
+        for (i, numeral) in SMALL.iter().enumerate() {
+            let r = Roman::from(i as u32 + 1).unwrap();
+            assert_eq!(*numeral, r.to_string());
+        }
+    }
+
+    #[test]
+    fn minify_and_expand() {
+        let r = Roman::from(449u32).unwrap();
+        let expanded = r.expand();
+        assert_eq!("CCCCXXXXVIIII", expanded.to_string());
+        assert_eq!(r, expanded);
+        assert_eq!("CDXLIX", expanded.minify().to_string());
+        for n in 1..=3999u32 {
+            let r = Roman::from(n).unwrap();
+            let digits = r.expand().to_digits();
+            assert_eq!(n, septem::Digit::value_of::<u32>(&digits));
+        }
+    }
+
+    #[test]
+    fn breakdown_sums_to_value() {
+        let r = Roman::from(3449u32).unwrap();
+        let groups = r.breakdown();
+        assert_eq!(("MMM".to_string(), 3000), groups[0]);
+        assert_eq!(3449, groups.iter().map(|(_, v)| v).sum::<u32>());
+        let rendered: String = groups.into_iter().map(|(g, _)| g).collect();
+        assert_eq!(r.to_string(), rendered);
+    }
+
+    #[test]
+    fn append_to() {
+        let mut buf = String::new();
+        for n in &[1u32, 4, 9] {
+            let r = Roman::from(*n).unwrap();
+            r.append_to(&mut buf);
+            r.append_lowercase_to(&mut buf);
+        }
+        assert_eq!("IiIVivIXix", buf);
+        assert_eq!(2, Roman::from(4u32).unwrap().len());
+    }
+
+    #[test]
+    fn roman_macro() {
+        const CHAPTER: Roman = septem::roman!("XIV");
+        assert_eq!(Roman::from(14u32).unwrap(), CHAPTER);
+        assert_eq!(3999, *septem::roman!("MMMCMXCIX"));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Roman::from(3000u32).unwrap();
+        let b = Roman::from(999u32).unwrap();
+        assert_eq!(3999, *(a + b));
+        assert_eq!(2001, *(a - b));
+        assert!(b.checked_sub(a).is_none());
+    }
+
+    #[test]
+    fn compare_with_int_and_str() {
+        let r = Roman::from(1994u32).unwrap();
+        assert_eq!(r, 1994u32);
+        assert_ne!(r, 1995u32);
+        assert_eq!(r, "MCMXCIV");
+        assert_ne!(r, "mcmxciv");
+        assert_ne!(r, "MCMXCIVI");
+        let four = Roman::from(4u32).unwrap().expand();
+        assert_eq!(four, "IV");
+        assert_ne!(four, "IIII");
+    }
+
+    #[test]
+    fn try_sum_overflow() {
+        let pages = vec![Roman::from(1000u32).unwrap(); 3];
+        assert_eq!(3000, *Roman::try_sum(pages).unwrap());
+        match Roman::try_sum(vec![Roman::max_value(), Roman::min_value()]) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+        match Roman::try_sum(vec![Roman::max_value(); 20_000]) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_overflow() {
+        let _ = Roman::max_value() + Roman::min_value();
+    }
+}
+
+#[cfg(all(test, feature = "archaic"))]
+mod archaic_tests {
+    extern crate septem;
+    use self::septem::{Digit, Roman};
+
+    #[test]
+    fn apostrophus_round_trip() {
+        for &n in &[
+            1u32, 500, 999, 1000, 1994, 3999, 4000, 5000, 11_101, 123_456, 399_999,
+        ] {
+            let s = Roman::from_unchecked(n).to_apostrophus();
+            let digits = Digit::from_apostrophus(&s).unwrap();
+            assert_eq!(n, Digit::value_of::<u32>(&digits), "{}", s);
+        }
+    }
+
+    #[test]
+    fn apostrophus_clusters() {
+        assert_eq!("CIↃ", Roman::from(1000u32).unwrap().to_apostrophus());
+        assert_eq!(
+            "CCCIↃↃↃIↃↃↃ",
+            Roman::from_unchecked(150_000u32).to_apostrophus()
+        );
+        assert_eq!("XIV", Roman::from(14u32).unwrap().to_apostrophus());
+    }
 }
@@ -0,0 +1,40 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    extern crate septem;
+    use self::septem::Roman;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Chapter {
+        number: Roman,
+        #[serde(with = "septem::as_value")]
+        page: Roman,
+    }
+
+    #[test]
+    fn round_trip() {
+        let chapter = Chapter {
+            number: Roman::from(14u32).unwrap(),
+            page: Roman::from(220u32).unwrap(),
+        };
+        let json = serde_json::to_string(&chapter).unwrap();
+        assert_eq!(r#"{"number":"XIV","page":220}"#, json);
+        assert_eq!(chapter, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn serializes_canonical_form() {
+        let expanded = Roman::from(1994u32).unwrap().expand();
+        let json = serde_json::to_string(&expanded).unwrap();
+        assert_eq!(r#""MCMXCIV""#, json);
+        let parsed: Roman = serde_json::from_str(&json).unwrap();
+        assert_eq!(expanded, parsed);
+        assert_eq!(expanded.minify().to_string(), parsed.to_string());
+    }
+
+    #[test]
+    fn invalid_numeral() {
+        let err = serde_json::from_str::<Roman>(r#""XIVA""#).unwrap_err();
+        assert!(err.to_string().contains("not a valid Roman digit"));
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    extern crate septem;
+    use self::septem::{Digit, Error, Vinculum};
+
+    #[test]
+    fn small_values_are_standard() {
+        let v = Vinculum::from(3999u32).unwrap();
+        assert_eq!("MMMCMXCIX", v.to_string());
+    }
+
+    #[test]
+    fn round_trip() {
+        for &n in &[4000u32, 5000, 13_999, 444_444, 1_000_000, Vinculum::MAX] {
+            let v = Vinculum::from(n).unwrap();
+            let parsed: Vinculum = v.to_string().parse().unwrap();
+            assert_eq!(n, *parsed);
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        match Digit::from_int_vinculum(Vinculum::MAX + 1) {
+            Err(Error::OutOfRange(n)) => assert_eq!(Vinculum::MAX + 1, n),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn display_padding() {
+        let v = Vinculum::from(5500u32).unwrap();
+        assert_eq!("V\u{305}D  ", format!("{:<5}", v));
+        assert_eq!("**V\u{305}D", format!("{:*>5}", v));
+        assert_eq!("V\u{305}D", format!("{:.1}", v));
+    }
+
+    #[test]
+    fn from_int_vinculum_wide() {
+        assert_eq!(
+            vec![(Digit::V, true)],
+            Digit::from_int_vinculum(5000u64).unwrap()
+        );
+        match Digit::from_int_vinculum(u64::MAX) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+        match Digit::from_int_vinculum(-1i32) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+        assert_eq!(
+            Digit::from_int_vinculum(1_000_000u64).unwrap(),
+            Vinculum::from(1_000_000u64).unwrap().to_digits()
+        );
+        match Vinculum::from(u64::MAX) {
+            Err(Error::Overflow) => {}
+            _ => panic!(),
+        }
+    }
+}